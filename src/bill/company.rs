@@ -0,0 +1,201 @@
+//! Organizations that issue bills
+//!
+//! Only the issuers of service types with a single national organization (fixed line, tax and
+//! driving offenses) are bundled. Company codes of provincial water, electricity and gas companies,
+//! mobile operators and municipalities are assigned by each service and there is no published list
+//! to bundle and keep up to date, so they are loaded into a [CompanyRegistry] by the caller,
+//! e.g. from a table that is already maintained for a payment UI.
+
+use std::borrow::Cow;
+use std::collections::HashMap;
+
+use super::{BillID, BillType};
+
+/// Organization that issues a bill, see [BillID::company] and [CompanyRegistry]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+#[derive(PartialEq, Eq, Debug, Clone, Hash)]
+pub struct Company {
+    name: Cow<'static, str>,
+    persian_name: Cow<'static, str>,
+}
+
+impl Company {
+    /// Builds a company with English and Persian names
+    /// ```rust
+    /// use rust_persian_tools::bill::Company;
+    ///
+    /// let company = Company::new("Tehran Electricity", String::from("برق تهران بزرگ"));
+    /// assert_eq!(company.get_persian_name(), "برق تهران بزرگ");
+    /// ```
+    pub fn new(
+        name: impl Into<Cow<'static, str>>,
+        persian_name: impl Into<Cow<'static, str>>,
+    ) -> Self {
+        Company {
+            name: name.into(),
+            persian_name: persian_name.into(),
+        }
+    }
+
+    const fn national(name: &'static str, persian_name: &'static str) -> Self {
+        Company {
+            name: Cow::Borrowed(name),
+            persian_name: Cow::Borrowed(persian_name),
+        }
+    }
+
+    pub fn get_name(&self) -> &str {
+        &self.name
+    }
+    pub fn get_persian_name(&self) -> &str {
+        &self.persian_name
+    }
+}
+
+/// Issuer of every bill of a service type that is issued by a single national organization
+pub(super) fn get_national_company(r#type: BillType) -> Option<Company> {
+    Some(match r#type {
+        BillType::Tel => {
+            Company::national("Telecommunication Company of Iran", "شرکت مخابرات ایران")
+        }
+        BillType::Tax => Company::national(
+            "Iranian National Tax Administration",
+            "سازمان امور مالیاتی کشور",
+        ),
+        BillType::DrivingOffense => Company::national("Traffic Police", "پلیس راهور"),
+        BillType::Water
+        | BillType::Electricity
+        | BillType::Gas
+        | BillType::Mobile
+        | BillType::Municipality => {
+            return None;
+        }
+    })
+}
+
+/// Issuers of bills by service type and company code
+///
+/// Registered companies are looked up before the bundled national issuers, see [the module](self).
+///
+/// ```rust
+/// use rust_persian_tools::bill::{BillID, BillType, Company, CompanyRegistry};
+///
+/// let mut registry = CompanyRegistry::new();
+/// registry.insert(
+///     BillType::Electricity,
+///     "123",
+///     Company::new("Tehran Electricity", "برق تهران بزرگ"),
+/// );
+///
+/// let bill_id = BillID::new("1234", "123", BillType::Electricity).unwrap();
+/// assert_eq!(
+///     registry.get(&bill_id).unwrap().get_persian_name(),
+///     "برق تهران بزرگ"
+/// );
+/// let other = BillID::new("1234", "124", BillType::Electricity).unwrap();
+/// assert_eq!(registry.get(&other), None);
+/// let tel = BillID::new("1234", "001", BillType::Tel).unwrap();
+/// assert_eq!(registry.get(&tel).unwrap().get_name(), "Telecommunication Company of Iran");
+///
+/// // a maintained table can be collected as well
+/// let registry: CompanyRegistry = [
+///     (BillType::Electricity, "123", Company::new("Tehran Electricity", "برق تهران بزرگ")),
+///     (BillType::Gas, "456", Company::new("Tehran Gas", "گاز تهران")),
+/// ]
+/// .into_iter()
+/// .collect();
+/// assert_eq!(registry.get(&bill_id).unwrap().get_name(), "Tehran Electricity");
+/// ```
+#[derive(PartialEq, Eq, Debug, Clone, Default)]
+pub struct CompanyRegistry {
+    companies: HashMap<(BillType, String), Company>,
+}
+
+impl CompanyRegistry {
+    /// Registry with only the bundled national issuers
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Registers the issuer of bills with the service type and 3-digit company code\
+    /// Returns the company that was registered before, if any
+    pub fn insert(
+        &mut self,
+        r#type: BillType,
+        company_code: impl Into<String>,
+        company: Company,
+    ) -> Option<Company> {
+        self.companies
+            .insert((r#type, company_code.into()), company)
+    }
+
+    /// Issuer of bills with the service type and company code
+    pub fn get_company(&self, r#type: BillType, company_code: &str) -> Option<Company> {
+        self.companies
+            .get(&(r#type, company_code.to_string()))
+            .cloned()
+            .or_else(|| get_national_company(r#type))
+    }
+
+    /// Issuer of the bill
+    pub fn get(&self, bill_id: &BillID) -> Option<Company> {
+        self.get_company(bill_id.r#type, &bill_id.company_code)
+    }
+}
+
+impl<S: Into<String>> Extend<(BillType, S, Company)> for CompanyRegistry {
+    fn extend<I: IntoIterator<Item = (BillType, S, Company)>>(&mut self, iter: I) {
+        for (r#type, company_code, company) in iter {
+            self.insert(r#type, company_code, company);
+        }
+    }
+}
+
+impl<S: Into<String>> FromIterator<(BillType, S, Company)> for CompanyRegistry {
+    fn from_iter<I: IntoIterator<Item = (BillType, S, Company)>>(iter: I) -> Self {
+        let mut registry = Self::new();
+        registry.extend(iter);
+        registry
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn registry_test() {
+        let mut registry = CompanyRegistry::new();
+        assert_eq!(registry.get_company(BillType::Water, "001"), None);
+        assert_eq!(
+            registry
+                .get_company(BillType::Tax, "001")
+                .map(|company| company.get_name().to_string()),
+            Some("Iranian National Tax Administration".to_string())
+        );
+
+        let water = Company::new("Water", "آب".to_string());
+        assert_eq!(registry.insert(BillType::Water, "001", water.clone()), None);
+        assert_eq!(
+            registry.get_company(BillType::Water, "001"),
+            Some(water.clone())
+        );
+        assert_eq!(registry.get_company(BillType::Water, "002"), None);
+        assert_eq!(registry.get_company(BillType::Gas, "001"), None);
+
+        // registered companies come before the national ones
+        let tel = Company::new("Tel", "تلفن");
+        registry.insert(BillType::Tel, "001", tel.clone());
+        assert_eq!(registry.get_company(BillType::Tel, "001"), Some(tel));
+        assert_eq!(
+            registry
+                .get_company(BillType::Tel, "002")
+                .map(|company| company.get_persian_name().to_string()),
+            Some("شرکت مخابرات ایران".to_string())
+        );
+        assert_eq!(
+            registry.insert(BillType::Water, "001", Company::new("", "")),
+            Some(water)
+        );
+    }
+}
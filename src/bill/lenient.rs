@@ -0,0 +1,246 @@
+use std::str::FromStr;
+
+use crate::digits::{ar_to_en, fa_to_en};
+
+use super::{base11_checksum, Bill, BillError};
+
+/// Length of a standard barcode: 13 digits of Bill ID and 13 digits of Payment ID
+const BARCODE_LENGTH: usize = 26;
+/// Length of the barcode variant that pads Payment ID to 17 digits
+const PADDED_BARCODE_LENGTH: usize = 30;
+/// Shortest barcode with trimmed leading zeros: 13 digits of Payment ID and a 6-digit Bill ID
+const MIN_TRIMMED_LENGTH: usize = 19;
+
+/// Checksum that failed in [Bill::parse_lenient]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Clone, Copy, Debug, Hash, PartialEq, Eq)]
+pub enum Checksum {
+    /// Last digit of Bill ID
+    BillID,
+    /// Checksum1, the digit before the last of Payment ID
+    PaymentID,
+    /// Checksum2, the last digit of Payment ID that checks Bill and Payment IDs together
+    Combined,
+}
+
+impl std::fmt::Display for Checksum {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Checksum::BillID => write!(f, "Bill ID"),
+            Checksum::PaymentID => write!(f, "Payment ID"),
+            Checksum::Combined => write!(f, "combined"),
+        }
+    }
+}
+
+/// Diagnostics of [Bill::parse_lenient]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Clone, Copy, Debug, Hash, PartialEq, Eq, thiserror::Error)]
+pub enum BarcodeError {
+    #[error("Character {character:?} at {position} is not a digit")]
+    InvalidCharacter {
+        character: char,
+        /// byte offset of the character in the input
+        position: usize,
+    },
+    #[error("Barcode has {length} digits, expected {BARCODE_LENGTH} or {PADDED_BARCODE_LENGTH}")]
+    InvalidLength { length: usize },
+    #[error("Padding digits of the {PADDED_BARCODE_LENGTH} digit barcode must be zeros")]
+    InvalidPadding,
+    #[error("{checksum} checksum doesn't match, expected {expected} but found {found}")]
+    ChecksumMismatch {
+        checksum: Checksum,
+        expected: u8,
+        found: u8,
+    },
+    #[error(transparent)]
+    Bill(#[from] BillError),
+}
+
+/// Characters that scanners and copy/paste put between the digits
+fn is_separator(c: char) -> bool {
+    c.is_whitespace()
+        || "-_\u{200c}\u{200e}\u{200f}\u{2010}\u{2011}\u{2012}\u{2013}\u{2014}\u{2212}".contains(c)
+}
+
+/// English digits of the barcode without separators
+fn normalize(input: &str) -> Result<String, BarcodeError> {
+    // digits are converted one char to one char, so the chars are paired with the input
+    let digits = ar_to_en(fa_to_en(input));
+    input
+        .char_indices()
+        .zip(digits.chars())
+        .filter(|(_, c)| !is_separator(*c))
+        .map(|((position, character), c)| match c {
+            '0'..='9' => Ok(c),
+            // report the character as it was in the input
+            _ => Err(BarcodeError::InvalidCharacter {
+                character,
+                position,
+            }),
+        })
+        .collect()
+}
+
+/// Checks the checksum digit at `index` of `digits` against the checksum of the digits before it
+fn check(digits: &str, index: usize, checksum: Checksum) -> Result<(), BarcodeError> {
+    let expected = base11_checksum(&digits[..index])?;
+    let found = digits.as_bytes()[index] - b'0';
+    if expected != found {
+        return Err(BarcodeError::ChecksumMismatch {
+            checksum,
+            expected,
+            found,
+        });
+    }
+    Ok(())
+}
+
+impl Bill {
+    /// Loads a bill from a scanned or pasted barcode
+    ///
+    /// Unlike [Bill::from_str](std::str::FromStr::from_str) the barcode may:
+    /// - have Persian or Arabic digits
+    /// - have spaces, dashes and other separators between the digits
+    /// - have leading zeros of the Bill ID trimmed
+    /// - be 30 digits long where the Payment ID is padded to 17 digits
+    ///
+    /// When a checksum fails the error tells which one failed
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rust_persian_tools::bill::{BarcodeError, Bill, Checksum};
+    ///
+    /// let bill = Bill::parse_lenient("۷۷۴۸۳۱۷۸۰۰۱۴۲ - ۰۰۰۰۰۰۱۷۷۰۱۶۰").unwrap();
+    /// assert_eq!(bill.to_string(), "77483178001420000001770160");
+    ///
+    /// let bill = Bill::parse_lenient("7748317800142 00000000001770160").unwrap();
+    /// assert_eq!(bill.get_payment_id(), "1770160");
+    ///
+    /// assert_eq!(
+    ///     Bill::parse_lenient("77483178001420000001770161"),
+    ///     Err(BarcodeError::ChecksumMismatch {
+    ///         checksum: Checksum::Combined,
+    ///         expected: 0,
+    ///         found: 1
+    ///     })
+    /// );
+    /// ```
+    pub fn parse_lenient(barcode: impl AsRef<str>) -> Result<Bill, BarcodeError> {
+        let digits = normalize(barcode.as_ref())?;
+
+        let digits = match digits.len() {
+            PADDED_BARCODE_LENGTH => {
+                if digits[13..17].bytes().any(|b| b != b'0') {
+                    return Err(BarcodeError::InvalidPadding);
+                }
+                format!("{}{}", &digits[..13], &digits[17..])
+            }
+            MIN_TRIMMED_LENGTH..=BARCODE_LENGTH => format!("{:0>26}", digits),
+            length => return Err(BarcodeError::InvalidLength { length }),
+        };
+
+        let bill_id = digits[..13].trim_start_matches('0');
+        if bill_id.len() < 6 {
+            return Err(BillError::InvalidBillIDLength.into());
+        }
+        let payment_id = digits[13..].trim_start_matches('0');
+        if payment_id.len() < 6 {
+            return Err(BillError::InvalidPaymentIDLength.into());
+        }
+
+        check(bill_id, bill_id.len() - 1, Checksum::BillID)?;
+        check(payment_id, payment_id.len() - 2, Checksum::PaymentID)?;
+        let merged = format!("{bill_id}{payment_id}");
+        check(&merged, merged.len() - 1, Checksum::Combined)?;
+
+        Ok(Bill::from_str(&digits)?)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::bill::{BillID, BillType, CurrencyType, PaymentID};
+
+    #[test]
+    fn parse_lenient_test() {
+        let expected = Bill::from_str("77483178001420000001770160").unwrap();
+        for barcode in [
+            "77483178001420000001770160",
+            "7748317800142 0000001770160",
+            "  7748-3178-0014-2000-0001-7701-60\n",
+            "۷۷۴۸۳۱۷۸۰۰۱۴۲۰۰۰۰۰۰۱۷۷۰۱۶۰",
+            "٧٧٤٨٣١٧٨٠٠١٤٢٠٠٠٠٠٠١٧٧٠١٦٠",
+            "774831780014200000000001770160",
+        ] {
+            assert_eq!(
+                Bill::parse_lenient(barcode),
+                Ok(expected.clone()),
+                "{barcode}"
+            );
+        }
+
+        // leading zeros of Bill ID trimmed
+        let bill_id = BillID::new("1234", "001", BillType::Water).unwrap();
+        let payment_id = PaymentID::new(50, 3, 2, &bill_id).unwrap();
+        let barcode = Bill::new(bill_id, payment_id).unwrap().to_string();
+        assert!(barcode.starts_with("0000"));
+        for trimmed in [barcode.trim_start_matches('0'), &barcode[2..]] {
+            let parsed = Bill::parse_lenient(trimmed).unwrap();
+            assert_eq!(parsed.to_string(), barcode);
+            assert_eq!(parsed.amount(CurrencyType::Rials), 50000);
+        }
+    }
+
+    #[test]
+    fn parse_lenient_diagnostics_test() {
+        assert_eq!(
+            Bill::parse_lenient("7748317800142 ۰۰۰۰۰۰x1770160"),
+            Err(BarcodeError::InvalidCharacter {
+                character: 'x',
+                position: 26
+            })
+        );
+        assert_eq!(
+            Bill::parse_lenient("۷۷۴۸۳۱۷۸۰۰۱۴۲/۰۰۰۰۰۰۱۷۷۰۱۶۰"),
+            Err(BarcodeError::InvalidCharacter {
+                character: '/',
+                position: 26
+            })
+        );
+        assert_eq!(
+            Bill::parse_lenient("7748317800142000000001770160"),
+            Err(BarcodeError::InvalidLength { length: 28 })
+        );
+        assert_eq!(
+            Bill::parse_lenient("123"),
+            Err(BarcodeError::InvalidLength { length: 3 })
+        );
+        assert_eq!(
+            Bill::parse_lenient("774831780014210000000001770160"),
+            Err(BarcodeError::InvalidPadding)
+        );
+        assert_eq!(
+            Bill::parse_lenient("77483178001430000001770160"),
+            Err(BarcodeError::ChecksumMismatch {
+                checksum: Checksum::BillID,
+                expected: 2,
+                found: 3
+            })
+        );
+        assert_eq!(
+            Bill::parse_lenient("77483178001420000001770170"),
+            Err(BarcodeError::ChecksumMismatch {
+                checksum: Checksum::PaymentID,
+                expected: 6,
+                found: 7
+            })
+        );
+        assert_eq!(
+            Bill::parse_lenient("0000000000000000001770160"),
+            Err(BarcodeError::Bill(BillError::InvalidBillIDLength))
+        );
+    }
+}
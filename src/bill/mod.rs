@@ -57,6 +57,20 @@
 //! assert_eq!(bill.amount(CurrencyType::Tomans), 1700);
 //! assert_eq!(bill.to_string(), "77483178001420000001770160");
 //! ```
+//!
+//! #### Serde Integration
+//! With `serde` Cargo feature, [BillID], [PaymentID] and [Bill] are serialized as strings and validated
+//! on deserialize, the same as [FromStr]. `bill::serde` module has the same as helpers
+//! for `serialize_with`/`deserialize_with` and optional fields.
+
+pub mod company;
+mod lenient;
+#[cfg(feature = "jalali")]
+pub mod period;
+#[cfg(feature = "serde")]
+pub mod serde;
+pub use company::{Company, CompanyRegistry};
+pub use lenient::{BarcodeError, Checksum};
 
 use num_derive::FromPrimitive;
 use num_traits::FromPrimitive;
@@ -64,7 +78,7 @@ use std::convert::From;
 use std::str::FromStr;
 use std::string::ToString;
 
-#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
 #[derive(Clone, Copy, Debug, Hash, PartialEq, Eq, thiserror::Error)]
 pub enum BillError {
     #[error("Barcode length must be 26 chars")]
@@ -96,7 +110,7 @@ pub enum BillError {
 /// use rust_persian_tools::bill::BillType;
 /// assert_eq!(FromPrimitive::from_u8(2), Some(BillType::Electricity));
 /// ```
-#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
 #[derive(FromPrimitive, Clone, Copy, PartialEq, Eq, Debug, Hash)]
 pub enum BillType {
     /// آب  
@@ -125,7 +139,7 @@ pub enum BillType {
     DrivingOffense = 8,
 }
 
-#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
 #[derive(Clone, Copy, PartialEq, Eq, Debug, Hash)]
 pub enum CurrencyType {
     Rials,
@@ -139,7 +153,12 @@ pub enum CurrencyType {
 ///
 /// Checksum is calculated via [ISSN Modulo 11 check digit](https://www.activebarcode.com/codes/checkdigit/modulo11)
 ///
-#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+/// With `serde` Cargo feature it is serialized as a string and validated on deserialize.
+#[cfg_attr(
+    feature = "serde",
+    derive(::serde::Serialize, ::serde::Deserialize),
+    serde(try_from = "String", into = "String")
+)]
 #[derive(Debug, PartialEq, Eq, Clone, Hash)]
 pub struct BillID {
     /// Maximum 8-digit Company Internal File ID
@@ -201,6 +220,33 @@ impl BillID {
             checksum,
         })
     }
+
+    /// Returns the organization that issued the bill if the service type is issued by a single
+    /// national organization, use [CompanyRegistry] for the companies of the other service types
+    ///
+    /// ```rust
+    /// use rust_persian_tools::bill::BillID;
+    /// use std::str::FromStr;
+    ///
+    /// let company = BillID::from_str("7748317800142").unwrap().company().unwrap();
+    /// assert_eq!(company.get_persian_name(), "شرکت مخابرات ایران");
+    /// assert_eq!(company.get_name(), "Telecommunication Company of Iran");
+    /// ```
+    pub fn company(&self) -> Option<Company> {
+        company::get_national_company(self.r#type)
+    }
+}
+impl TryFrom<String> for BillID {
+    type Error = BillError;
+
+    fn try_from(s: String) -> Result<Self, Self::Error> {
+        BillID::from_str(&s)
+    }
+}
+impl From<BillID> for String {
+    fn from(bill_id: BillID) -> Self {
+        bill_id.to_string()
+    }
 }
 impl ToString for BillID {
     /// Returns String representation of Bill ID
@@ -220,7 +266,13 @@ impl ToString for BillID {
 /// Checksums are calculated via [ISSN Modulo 11 check digit](https://www.activebarcode.com/codes/checkdigit/modulo11)  \
 /// Checksum1 is the checksum for Payment ID itself and only checks digits in Payment ID  \
 /// Checksum2 is the checksum for Bill ID and Payment ID concatenated together and checks validity of relation between two IDs
-#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+///
+/// With `serde` Cargo feature it is serialized as a string and checksum1 is validated on deserialize.
+#[cfg_attr(
+    feature = "serde",
+    derive(::serde::Serialize, ::serde::Deserialize),
+    serde(try_from = "String", into = "String")
+)]
 #[derive(Debug, PartialEq, Eq, Clone, Copy, Hash)]
 pub struct PaymentID {
     /// Amount in scale 1000:1 (1000 will be 1)
@@ -284,6 +336,20 @@ impl std::str::FromStr for PaymentID {
     }
 }
 
+impl TryFrom<String> for PaymentID {
+    type Error = BillError;
+
+    fn try_from(s: String) -> Result<Self, Self::Error> {
+        PaymentID::from_str(&s)
+    }
+}
+
+impl From<PaymentID> for String {
+    fn from(payment_id: PaymentID) -> Self {
+        payment_id.to_string()
+    }
+}
+
 impl ToString for PaymentID {
     /// Returns String representation of Bill ID
     fn to_string(&self) -> String {
@@ -329,7 +395,13 @@ impl PaymentID {
 
 /// Container for Both Bill and Payment IDs  \
 /// You must use this type to extract all information about the bill  
-#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+///
+/// With `serde` Cargo feature it is serialized as the barcode and validated on deserialize.
+#[cfg_attr(
+    feature = "serde",
+    derive(::serde::Serialize, ::serde::Deserialize),
+    serde(try_from = "String", into = "String")
+)]
 #[derive(Debug, PartialEq, Eq, Clone, Hash)]
 pub struct Bill {
     pub bill_id: BillID,
@@ -390,6 +462,20 @@ impl Bill {
     }
 }
 
+impl TryFrom<String> for Bill {
+    type Error = BillError;
+
+    fn try_from(s: String) -> Result<Self, Self::Error> {
+        Bill::from_str(&s)
+    }
+}
+
+impl From<Bill> for String {
+    fn from(bill: Bill) -> Self {
+        bill.to_string()
+    }
+}
+
 impl ToString for Bill {
     /// Generates Barcode from Bill struct
     fn to_string(&self) -> String {
@@ -566,6 +652,22 @@ mod tests {
         assert!(BillID::from_str("2234322344613").is_err());
     }
 
+    #[test]
+    fn bill_company_test() {
+        let tax = BillID::new("1234", "001", BillType::Tax).unwrap();
+        assert_eq!(
+            tax.company().as_ref().map(|c| c.get_persian_name()),
+            Some("سازمان امور مالیاتی کشور")
+        );
+        let fine = BillID::new("1234", "001", BillType::DrivingOffense).unwrap();
+        assert_eq!(
+            fine.company().as_ref().map(|c| c.get_name()),
+            Some("Traffic Police")
+        );
+        let water = BillID::new("1234", "001", BillType::Water).unwrap();
+        assert_eq!(water.company(), None);
+    }
+
     #[test]
     fn bill_payment_id_is_valid() {
         // Check first checksum (payment id verification)
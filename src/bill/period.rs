@@ -0,0 +1,187 @@
+use crate::jalali::{days_in_month, JalaliDate};
+
+use super::{BillError, PaymentID};
+
+/// Length of the billing periods of a company
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Clone, Copy, Debug, Hash, PartialEq, Eq, Default)]
+pub enum BillingCycle {
+    /// Period 1 is فروردین and period 12 is اسفند
+    #[default]
+    Monthly,
+    /// Period 1 is فروردین and اردیبهشت and period 6 is بهمن and اسفند
+    BiMonthly,
+}
+
+impl BillingCycle {
+    /// Number of months in a period
+    pub fn months(&self) -> u8 {
+        match self {
+            BillingCycle::Monthly => 1,
+            BillingCycle::BiMonthly => 2,
+        }
+    }
+}
+
+/// Jalali dates of a billing period, both ends are inclusive
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Clone, Copy, Debug, Hash, PartialEq, Eq)]
+pub struct BillingPeriod {
+    pub start: JalaliDate,
+    pub end: JalaliDate,
+}
+
+impl BillingPeriod {
+    /// Checks whether `date` is in the period
+    pub fn contains(&self, date: JalaliDate) -> bool {
+        self.start <= date && date <= self.end
+    }
+
+    /// Checks whether more than `grace_days` days have passed from the end of the period on `date`
+    ///
+    /// ```rust
+    /// use rust_persian_tools::bill::period::BillingPeriod;
+    /// use rust_persian_tools::jalali::JalaliDate;
+    ///
+    /// let period = BillingPeriod {
+    ///     start: JalaliDate::new(1403, 1, 1).unwrap(),
+    ///     end: JalaliDate::new(1403, 1, 31).unwrap(),
+    /// };
+    /// assert!(!period.is_expired(JalaliDate::new(1403, 2, 10).unwrap(), 10));
+    /// assert!(period.is_expired(JalaliDate::new(1403, 2, 11).unwrap(), 10));
+    /// ```
+    pub fn is_expired(&self, date: JalaliDate, grace_days: u32) -> bool {
+        match self.end.checked_add_days(grace_days as i64) {
+            Some(due) => date > due,
+            None => false,
+        }
+    }
+}
+
+impl PaymentID {
+    /// Full Jalali year of the bill\
+    /// The year code is the last digit of the year, so the latest year with that last digit
+    /// that is not after `reference` is taken
+    ///
+    /// ```rust
+    /// use rust_persian_tools::bill::PaymentID;
+    /// use rust_persian_tools::jalali::JalaliDate;
+    /// use std::str::FromStr;
+    ///
+    /// let payment_id = PaymentID::from_str("1770160").unwrap();
+    /// let today = JalaliDate::new(1409, 3, 1).unwrap();
+    /// assert_eq!(payment_id.full_year(today), 1407);
+    /// ```
+    pub fn full_year(&self, reference: JalaliDate) -> i32 {
+        let reference = reference.year();
+        reference - (reference - self.year as i32).rem_euclid(10)
+    }
+
+    /// Decodes year and period codes into the Jalali dates of the billing period
+    ///
+    /// Returns `Err(BillError::InvalidBillIDPeriod)` if the period code doesn't exist in the cycle
+    /// and `Err(BillError::InvalidBillIDYear)` if the year is out of the supported range
+    ///
+    /// ```rust
+    /// use rust_persian_tools::bill::period::{BillingCycle, BillingPeriod};
+    /// use rust_persian_tools::bill::PaymentID;
+    /// use rust_persian_tools::jalali::JalaliDate;
+    /// use std::str::FromStr;
+    ///
+    /// // year code 7 and period 01
+    /// let payment_id = PaymentID::from_str("1770160").unwrap();
+    /// let today = JalaliDate::new(1407, 3, 1).unwrap();
+    /// assert_eq!(
+    ///     payment_id.billing_period(BillingCycle::BiMonthly, today),
+    ///     Ok(BillingPeriod {
+    ///         start: JalaliDate::new(1407, 1, 1).unwrap(),
+    ///         end: JalaliDate::new(1407, 2, 31).unwrap(),
+    ///     })
+    /// );
+    /// ```
+    pub fn billing_period(
+        &self,
+        cycle: BillingCycle,
+        reference: JalaliDate,
+    ) -> Result<BillingPeriod, BillError> {
+        let months = cycle.months();
+        if self.period == 0 || self.period > 12 / months {
+            return Err(BillError::InvalidBillIDPeriod);
+        }
+        let year = self.full_year(reference);
+        let first_month = (self.period - 1) * months + 1;
+        let last_month = first_month + months - 1;
+
+        // months are valid here, only the year may be out of range
+        let last_day = days_in_month(year, last_month).unwrap_or_default();
+        match (
+            JalaliDate::new(year, first_month, 1),
+            JalaliDate::new(year, last_month, last_day),
+        ) {
+            (Ok(start), Ok(end)) => Ok(BillingPeriod { start, end }),
+            _ => Err(BillError::InvalidBillIDYear),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::bill::BillID;
+    use std::str::FromStr;
+
+    fn date(year: i32, month: u8, day: u8) -> JalaliDate {
+        JalaliDate::new(year, month, day).unwrap()
+    }
+
+    #[test]
+    fn full_year_test() {
+        let payment_id = PaymentID::from_str("1770160").unwrap();
+        assert_eq!(payment_id.full_year(date(1407, 1, 1)), 1407);
+        assert_eq!(payment_id.full_year(date(1410, 1, 1)), 1407);
+        assert_eq!(payment_id.full_year(date(1406, 12, 29)), 1397);
+    }
+
+    #[test]
+    fn billing_period_test() {
+        // year code 3
+        let bill_id = BillID::from_str("7748317800142").unwrap();
+        let payment_id = |period| PaymentID::new(10, 3, period, &bill_id).unwrap();
+        let today = date(1403, 11, 1);
+
+        assert_eq!(
+            payment_id(12).billing_period(BillingCycle::Monthly, today),
+            Ok(BillingPeriod {
+                start: date(1403, 12, 1),
+                end: date(1403, 12, 30),
+            })
+        );
+        assert_eq!(
+            payment_id(6).billing_period(BillingCycle::BiMonthly, date(1404, 1, 1)),
+            Ok(BillingPeriod {
+                start: date(1403, 11, 1),
+                end: date(1403, 12, 30),
+            })
+        );
+        assert_eq!(
+            payment_id(7).billing_period(BillingCycle::BiMonthly, today),
+            Err(BillError::InvalidBillIDPeriod)
+        );
+        assert_eq!(
+            payment_id(0).billing_period(BillingCycle::Monthly, today),
+            Err(BillError::InvalidBillIDPeriod)
+        );
+        assert_eq!(
+            payment_id(1).billing_period(BillingCycle::Monthly, date(2, 1, 1)),
+            Err(BillError::InvalidBillIDYear)
+        );
+
+        let period = payment_id(4)
+            .billing_period(BillingCycle::Monthly, today)
+            .unwrap();
+        assert!(period.contains(date(1403, 4, 31)));
+        assert!(!period.contains(date(1403, 5, 1)));
+        assert!(!period.is_expired(date(1403, 4, 31), 0));
+        assert!(period.is_expired(date(1403, 5, 1), 0));
+    }
+}
@@ -0,0 +1,328 @@
+//! [serde] helpers to serialize Bill ID, Payment ID and Bill as strings and to validate them on deserialize.
+//! Enabled if `serde` Cargo feature is enabled.
+//!
+//! [BillID], [PaymentID] and [Bill] do the same on their own, these helpers are for
+//! `serialize_with`/`deserialize_with` attributes and optional fields. Only strings are accepted,
+//! numbers are rejected since they lose leading zeros of the IDs.
+//!
+//! #### Example
+//! ```rust
+//! use rust_persian_tools::bill::serde::{bill_id_de, bill_id_ser, payment_id_de, payment_id_ser};
+//! use rust_persian_tools::bill::{BillID, PaymentID};
+//!
+//! #[derive(Debug, serde::Serialize, serde::Deserialize)]
+//! struct Payment {
+//!     #[serde(serialize_with = "bill_id_ser", deserialize_with = "bill_id_de")]
+//!     bill_id: BillID,
+//!     #[serde(serialize_with = "payment_id_ser", deserialize_with = "payment_id_de")]
+//!     payment_id: PaymentID,
+//! }
+//!
+//! let json_str = "{\"bill_id\":\"7748317800142\",\"payment_id\":\"1770160\"}";
+//! let payment: Payment = serde_json::from_str(json_str).unwrap();
+//! assert_eq!(payment.bill_id.to_string(), "7748317800142");
+//! assert_eq!(serde_json::to_string(&payment).unwrap(), json_str);
+//!
+//! let json_str_invalid = "{\"bill_id\":\"7748317800143\",\"payment_id\":\"1770160\"}";
+//! assert_eq!(
+//!     serde_json::from_str::<Payment>(json_str_invalid).err().unwrap().to_string(),
+//!     "Checksum doesn't match at line 1 column 26".to_string(),
+//! );
+//! ```
+//! Use [bill_de] and [bill_ser] for the barcode, which checks the relation
+//! between the IDs as well, and `*_option_de` functions for optional fields.
+
+use std::fmt::Display;
+use std::marker::PhantomData;
+use std::str::FromStr;
+
+use serde::{Deserializer, Serializer};
+
+use super::{Bill, BillID, PaymentID};
+
+/// Values that are loaded from their string form by [FromStr]
+trait Expecting {
+    const EXPECTING: &'static str;
+}
+
+impl Expecting for BillID {
+    const EXPECTING: &'static str = "expecting Bill ID, e.g. 7748317800142";
+}
+
+impl Expecting for PaymentID {
+    const EXPECTING: &'static str = "expecting Payment ID, e.g. 1770160";
+}
+
+impl Expecting for Bill {
+    const EXPECTING: &'static str = "expecting bill barcode, e.g. 77483178001420000001770160";
+}
+
+struct Parse<T>(PhantomData<T>);
+struct ParseOption<T>(PhantomData<T>);
+
+impl<'de, T> serde::de::Visitor<'de> for Parse<T>
+where
+    T: FromStr + Expecting,
+    T::Err: Display,
+{
+    type Value = T;
+
+    fn expecting(&self, formatter: &mut std::fmt::Formatter) -> std::fmt::Result {
+        formatter.write_str(T::EXPECTING)
+    }
+
+    fn visit_str<E>(self, s: &str) -> Result<Self::Value, E>
+    where
+        E: serde::de::Error,
+    {
+        T::from_str(s).map_err(serde::de::Error::custom)
+    }
+}
+
+impl<'de, T> serde::de::Visitor<'de> for ParseOption<T>
+where
+    T: FromStr + Expecting,
+    T::Err: Display,
+{
+    type Value = Option<T>;
+
+    fn expecting(&self, formatter: &mut std::fmt::Formatter) -> std::fmt::Result {
+        formatter.write_str(T::EXPECTING)
+    }
+
+    fn visit_none<E>(self) -> Result<Self::Value, E>
+    where
+        E: serde::de::Error,
+    {
+        Ok(None)
+    }
+
+    fn visit_some<D>(self, d: D) -> Result<Self::Value, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        d.deserialize_any(Parse(PhantomData)).map(Some)
+    }
+}
+
+/// Serializes [BillID] as a string in [serde].
+///
+/// For more info see [crate::bill::serde] module example.
+pub fn bill_id_ser<S>(bill_id: &BillID, serializer: S) -> Result<S::Ok, S::Error>
+where
+    S: Serializer,
+{
+    serializer.serialize_str(&bill_id.to_string())
+}
+
+/// Deserializes and validates [BillID] from a string in [serde].
+///
+/// For more info see [crate::bill::serde] module example.
+pub fn bill_id_de<'de, D>(deserializer: D) -> Result<BillID, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    deserializer.deserialize_any(Parse(PhantomData))
+}
+
+/// Deserializes and validates [BillID] (if exists) from a string in [serde].
+///
+/// For more info see [crate::bill::serde] module example.
+pub fn bill_id_option_de<'de, D>(deserializer: D) -> Result<Option<BillID>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    deserializer.deserialize_option(ParseOption(PhantomData))
+}
+
+/// Serializes [PaymentID] as a string in [serde].
+///
+/// For more info see [crate::bill::serde] module example.
+pub fn payment_id_ser<S>(payment_id: &PaymentID, serializer: S) -> Result<S::Ok, S::Error>
+where
+    S: Serializer,
+{
+    serializer.serialize_str(&payment_id.to_string())
+}
+
+/// Deserializes and validates [PaymentID] from a string in [serde].\
+/// Only checksum1 is validated, use [bill_de] to validate the relation with Bill ID as well.
+///
+/// For more info see [crate::bill::serde] module example.
+pub fn payment_id_de<'de, D>(deserializer: D) -> Result<PaymentID, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    deserializer.deserialize_any(Parse(PhantomData))
+}
+
+/// Deserializes and validates [PaymentID] (if exists) from a string in [serde].\
+/// Only checksum1 is validated, use [bill_option_de] to validate the relation with Bill ID as well.
+///
+/// For more info see [crate::bill::serde] module example.
+pub fn payment_id_option_de<'de, D>(deserializer: D) -> Result<Option<PaymentID>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    deserializer.deserialize_option(ParseOption(PhantomData))
+}
+
+/// Serializes [Bill] as a barcode string in [serde].
+///
+/// For more info see [crate::bill::serde] module example.
+pub fn bill_ser<S>(bill: &Bill, serializer: S) -> Result<S::Ok, S::Error>
+where
+    S: Serializer,
+{
+    serializer.serialize_str(&bill.to_string())
+}
+
+/// Deserializes and validates [Bill] from a barcode string in [serde].
+///
+/// For more info see [crate::bill::serde] module example.
+pub fn bill_de<'de, D>(deserializer: D) -> Result<Bill, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    deserializer.deserialize_any(Parse(PhantomData))
+}
+
+/// Deserializes and validates [Bill] (if exists) from a barcode string in [serde].
+///
+/// For more info see [crate::bill::serde] module example.
+pub fn bill_option_de<'de, D>(deserializer: D) -> Result<Option<Bill>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    deserializer.deserialize_option(ParseOption(PhantomData))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::bill::BillError;
+    use serde::{Deserialize, Serialize};
+
+    #[derive(Debug, PartialEq, Serialize, Deserialize)]
+    struct Payment {
+        #[serde(serialize_with = "bill_id_ser", deserialize_with = "bill_id_de")]
+        bill_id: BillID,
+        #[serde(serialize_with = "payment_id_ser", deserialize_with = "payment_id_de")]
+        payment_id: PaymentID,
+    }
+
+    #[derive(Debug, PartialEq, Serialize, Deserialize)]
+    struct Scanned {
+        #[serde(serialize_with = "bill_ser", deserialize_with = "bill_de")]
+        barcode: Bill,
+    }
+
+    #[derive(Debug, PartialEq, Deserialize)]
+    struct OptionalIds {
+        #[serde(default, deserialize_with = "bill_id_option_de")]
+        bill_id: Option<BillID>,
+        #[serde(default, deserialize_with = "payment_id_option_de")]
+        payment_id: Option<PaymentID>,
+        #[serde(default, deserialize_with = "bill_option_de")]
+        barcode: Option<Bill>,
+    }
+
+    #[test]
+    fn ser_de() {
+        let json_str = "{\"bill_id\":\"7748317800142\",\"payment_id\":\"1770160\"}";
+        let payment: Payment = serde_json::from_str(json_str).unwrap();
+        assert_eq!(
+            payment,
+            Payment {
+                bill_id: BillID::from_str("7748317800142").unwrap(),
+                payment_id: PaymentID::from_str("1770160").unwrap(),
+            }
+        );
+        assert_eq!(serde_json::to_string(&payment).unwrap(), json_str);
+
+        // numbers lose leading zeros of the IDs, so they are rejected
+        let json_str = "{\"bill_id\":7748317800142,\"payment_id\":1770160}";
+        assert!(serde_json::from_str::<Payment>(json_str).is_err());
+
+        let json_str = "{\"barcode\":\"77483178001420000001770160\"}";
+        let scanned: Scanned = serde_json::from_str(json_str).unwrap();
+        assert_eq!(scanned.barcode.get_payment_id(), "1770160");
+        assert_eq!(serde_json::to_string(&scanned).unwrap(), json_str);
+    }
+
+    #[test]
+    fn de_invalid() {
+        let json_str = "{\"bill_id\":\"7748317800143\",\"payment_id\":\"1770160\"}";
+        assert!(serde_json::from_str::<Payment>(json_str)
+            .err()
+            .unwrap()
+            .to_string()
+            .contains(&BillError::InvalidBillChecksum.to_string()));
+
+        let json_str = "{\"bill_id\":\"7748317800142\",\"payment_id\":\"177\"}";
+        assert!(serde_json::from_str::<Payment>(json_str)
+            .err()
+            .unwrap()
+            .to_string()
+            .contains(&BillError::InvalidPaymentIDLength.to_string()));
+
+        // checksum2 is only checked on the whole bill
+        let json_str = "{\"barcode\":\"77483178001420000001770161\"}";
+        assert!(serde_json::from_str::<Scanned>(json_str).is_err());
+
+        let json_str = "{\"barcode\":[]}";
+        assert!(serde_json::from_str::<Scanned>(json_str)
+            .err()
+            .unwrap()
+            .to_string()
+            .contains(Bill::EXPECTING));
+    }
+
+    #[test]
+    fn de_option() {
+        let ids: OptionalIds = serde_json::from_str("{}").unwrap();
+        assert_eq!(
+            ids,
+            OptionalIds {
+                bill_id: None,
+                payment_id: None,
+                barcode: None
+            }
+        );
+
+        let json_str = "{\"bill_id\":null,\"barcode\":\"77483178001420000001770160\"}";
+        let ids: OptionalIds = serde_json::from_str(json_str).unwrap();
+        assert_eq!(ids.bill_id, None);
+        assert_eq!(
+            ids.barcode.map(|bill| bill.get_bill_id()),
+            Some("7748317800142".to_string())
+        );
+
+        let json_str = "{\"payment_id\":\"1770170\"}";
+        assert!(serde_json::from_str::<OptionalIds>(json_str).is_err());
+    }
+
+    #[test]
+    fn derive() {
+        let bill = Bill::from_str("77483178001420000001770160").unwrap();
+        let json_str = "\"77483178001420000001770160\"";
+        assert_eq!(serde_json::to_string(&bill).unwrap(), json_str);
+        assert_eq!(serde_json::from_str::<Bill>(json_str).unwrap(), bill);
+        assert_eq!(
+            serde_json::to_string(&(&bill.bill_id, &bill.payment_id)).unwrap(),
+            "[\"7748317800142\",\"1770160\"]"
+        );
+        assert_eq!(
+            serde_json::from_str::<(BillID, PaymentID)>("[\"7748317800142\",\"1770160\"]").unwrap(),
+            (bill.bill_id, bill.payment_id)
+        );
+
+        assert!(serde_json::from_str::<BillID>("\"7748317800143\"")
+            .err()
+            .unwrap()
+            .to_string()
+            .contains(&BillError::InvalidBillChecksum.to_string()));
+        assert!(serde_json::from_str::<Bill>("\"77483178001420000001770161\"").is_err());
+        assert!(serde_json::from_str::<BillID>("7748317800142").is_err());
+    }
+}
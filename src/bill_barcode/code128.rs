@@ -0,0 +1,94 @@
+/// Bar and space widths of the Code 128 symbols, starting with a bar
+const PATTERNS: [&str; 107] = [
+    "212222", "222122", "222221", "121223", "121322", "131222", "122213", "122312", "132212",
+    "221213", "221312", "231212", "112232", "122132", "122231", "113222", "123122", "123221",
+    "223211", "221132", "221231", "213212", "223112", "312131", "311222", "321122", "321221",
+    "312212", "322112", "322211", "212123", "212321", "232121", "111323", "131123", "131321",
+    "112313", "132113", "132311", "211313", "231113", "231311", "112133", "112331", "132131",
+    "113123", "113321", "133121", "313121", "211331", "231131", "213113", "213311", "213131",
+    "311123", "311321", "331121", "312113", "312311", "332111", "314111", "221411", "431111",
+    "111224", "111422", "121124", "121421", "141122", "141221", "112214", "112412", "122114",
+    "122411", "142112", "142211", "241211", "221114", "413111", "241112", "134111", "111242",
+    "121142", "121241", "114212", "124112", "124211", "411212", "421112", "421211", "212141",
+    "214121", "412121", "111143", "111341", "131141", "114113", "114311", "411113", "411311",
+    "113141", "114131", "311141", "411131", "211412", "211214", "211232", "2331112",
+];
+
+/// Switches from code set C to code set B
+const CODE_B: u8 = 100;
+const START_C: u8 = 105;
+const STOP: u8 = 106;
+
+fn push(modules: &mut Vec<bool>, symbol: u8) {
+    for (i, width) in PATTERNS[symbol as usize].bytes().enumerate() {
+        let dark = i % 2 == 0;
+        modules.extend(std::iter::repeat_n(dark, (width - b'0') as usize));
+    }
+}
+
+/// Symbols of `digits` before the checksum and stop symbols\
+/// Pairs of digits are encoded with code set C and an odd last digit with code set B
+fn symbols(digits: &[u8]) -> Vec<u8> {
+    let mut symbols = vec![START_C];
+    let mut pairs = digits.chunks_exact(2);
+    for pair in pairs.by_ref() {
+        symbols.push((pair[0] - b'0') * 10 + pair[1] - b'0');
+    }
+    if let [digit] = pairs.remainder() {
+        // in code set B the value of a character is its ASCII code minus 32
+        symbols.extend([CODE_B, digit - b' ']);
+    }
+    symbols
+}
+
+/// Modules of a Code 128 barcode\
+/// `digits` must be ASCII digits
+pub(super) fn encode(digits: &[u8]) -> Vec<bool> {
+    let symbols = symbols(digits);
+    let checksum = symbols
+        .iter()
+        .enumerate()
+        .map(|(i, symbol)| i.max(1) * *symbol as usize)
+        .sum::<usize>()
+        % 103;
+
+    let mut modules = Vec::with_capacity((symbols.len() + 1) * 11 + 13);
+    for symbol in symbols {
+        push(&mut modules, symbol);
+    }
+    push(&mut modules, checksum as u8);
+    push(&mut modules, STOP);
+
+    modules
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn patterns_test() {
+        for (symbol, pattern) in PATTERNS.iter().enumerate() {
+            let widths: Vec<u8> = pattern.bytes().map(|b| b - b'0').collect();
+            let total: u8 = widths.iter().sum();
+            let bars: u8 = widths.iter().step_by(2).sum();
+            assert_eq!(total, if symbol == STOP as usize { 13 } else { 11 });
+            // bars of every symbol have an even number of modules
+            assert_eq!(bars % 2, 0, "{symbol}");
+        }
+    }
+
+    #[test]
+    fn code128_test() {
+        assert_eq!(symbols(b"123456"), vec![START_C, 12, 34, 56]);
+        assert_eq!(symbols(b"12345"), vec![START_C, 12, 34, CODE_B, 21]);
+
+        // checksum of "123456" is (105 + 12 + 2 * 34 + 3 * 56) % 103 = 44
+        let modules = encode(b"123456");
+        assert_eq!(modules.len(), 4 * 11 + 11 + 13);
+        let mut expected = Vec::new();
+        push(&mut expected, 44);
+        assert_eq!(&modules[44..55], expected.as_slice());
+        assert!(modules.ends_with(&[true, true, true, false, true, false, true, true]));
+    }
+}
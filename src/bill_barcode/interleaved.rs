@@ -0,0 +1,66 @@
+/// Width of a wide element in modules, a narrow element is one module
+const WIDE: usize = 3;
+
+/// Widths of the five bars (or spaces) of each digit, `true` is wide
+const DIGITS: [[bool; 5]; 10] = [
+    [false, false, true, true, false],
+    [true, false, false, false, true],
+    [false, true, false, false, true],
+    [true, true, false, false, false],
+    [false, false, true, false, true],
+    [true, false, true, false, false],
+    [false, true, true, false, false],
+    [false, false, false, true, true],
+    [true, false, false, true, false],
+    [false, true, false, true, false],
+];
+
+fn push(modules: &mut Vec<bool>, dark: bool, wide: bool) {
+    let width = if wide { WIDE } else { 1 };
+    modules.extend(std::iter::repeat_n(dark, width));
+}
+
+/// Modules of an Interleaved 2 of 5 barcode\
+/// `digits` must be an even number of ASCII digits
+pub(super) fn encode(digits: &[u8]) -> Vec<bool> {
+    let mut modules = Vec::with_capacity(digits.len() * 9 + 9);
+
+    // start: narrow bar, narrow space, narrow bar, narrow space
+    for dark in [true, false, true, false] {
+        push(&mut modules, dark, false);
+    }
+    for pair in digits.chunks_exact(2) {
+        // the first digit is encoded in the bars and the second one in the spaces
+        let bars = DIGITS[(pair[0] - b'0') as usize];
+        let spaces = DIGITS[(pair[1] - b'0') as usize];
+        for (bar, space) in bars.into_iter().zip(spaces) {
+            push(&mut modules, true, bar);
+            push(&mut modules, false, space);
+        }
+    }
+    // stop: wide bar, narrow space, narrow bar
+    push(&mut modules, true, true);
+    push(&mut modules, false, false);
+    push(&mut modules, true, false);
+
+    modules
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn interleaved_test() {
+        for pattern in DIGITS {
+            assert_eq!(pattern.iter().filter(|wide| **wide).count(), 2);
+        }
+
+        let modules = encode(b"12");
+        let expected: Vec<bool> = "101011101000101011100011101"
+            .chars()
+            .map(|c| c == '1')
+            .collect();
+        assert_eq!(modules, expected);
+    }
+}
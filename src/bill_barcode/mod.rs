@@ -0,0 +1,405 @@
+//! Renders utility bill barcodes (`bill-barcode` Cargo feature).
+//!
+//! Barcodes printed on bills are Interleaved 2 of 5 barcodes of [Bill::to_string](crate::bill::Bill),
+//! Code 128 is available as well. A barcode is a sequence of modules (narrowest bars and spaces)
+//! that can be written as SVG, PBM or PNG.
+//!
+//! #### Example
+//! ```rust
+//! use rust_persian_tools::bill::Bill;
+//! use rust_persian_tools::bill_barcode::{ImageOptions, Symbology};
+//! use std::str::FromStr;
+//!
+//! let bill = Bill::from_str("77483178001420000001770160").unwrap();
+//! let barcode = bill.barcode(Symbology::Interleaved2Of5);
+//! assert_eq!(barcode.modules().len(), 243);
+//!
+//! let options = ImageOptions::default();
+//! let svg = barcode.to_svg(&options).unwrap();
+//! assert!(svg.starts_with("<svg"));
+//! let png = barcode.to_png(&options).unwrap();
+//! assert_eq!(&png[1..4], b"PNG");
+//! ```
+
+mod code128;
+mod interleaved;
+mod png;
+
+use crate::bill::Bill;
+
+/// Largest width and height of an image, PNG doesn't allow larger sizes
+const MAX_IMAGE_SIZE: u32 = i32::MAX as u32;
+/// Largest size of the raster of an image in bytes, bounds the memory of PBM and PNG images
+const MAX_IMAGE_BYTES: u64 = i32::MAX as u64;
+
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Clone, Copy, Debug, Hash, PartialEq, Eq, thiserror::Error)]
+pub enum BarcodeRenderError {
+    #[error("Character {character:?} at {position} is not a digit")]
+    InvalidCharacter {
+        character: char,
+        /// byte offset of the character in the input
+        position: usize,
+    },
+    #[error("Interleaved 2 of 5 needs an even number of digits")]
+    OddLength,
+    #[error("Image must not be empty, larger than {MAX_IMAGE_SIZE} pixels in each side or larger than {MAX_IMAGE_BYTES} bytes")]
+    InvalidImageSize,
+}
+
+/// Barcode symbology
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Clone, Copy, Debug, Hash, PartialEq, Eq, Default)]
+pub enum Symbology {
+    /// Interleaved 2 of 5 with 3:1 wide to narrow ratio, used on paper bills
+    #[default]
+    Interleaved2Of5,
+    /// Code 128 with code set C
+    Code128,
+}
+
+/// Size of the rendered image in pixels\
+/// `module_width` and `height` must not be zero
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Clone, Copy, Debug, Hash, PartialEq, Eq)]
+pub struct ImageOptions {
+    /// Width of a module
+    pub module_width: u32,
+    /// Height of the bars
+    pub height: u32,
+    /// Number of white modules on each side of the barcode
+    pub quiet_zone: u32,
+}
+
+impl Default for ImageOptions {
+    fn default() -> Self {
+        ImageOptions {
+            module_width: 2,
+            height: 80,
+            quiet_zone: 10,
+        }
+    }
+}
+
+/// Encoded barcode, `true` modules are dark
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Clone, Debug, Hash, PartialEq, Eq)]
+pub struct Barcode {
+    modules: Vec<bool>,
+}
+
+impl Barcode {
+    /// Encodes a string of ASCII digits
+    ///
+    /// # Examples
+    /// ```
+    /// use rust_persian_tools::bill_barcode::{Barcode, BarcodeRenderError, Symbology};
+    ///
+    /// assert!(Barcode::encode("1234", Symbology::Interleaved2Of5).is_ok());
+    /// assert!(Barcode::encode("123", Symbology::Code128).is_ok());
+    /// assert_eq!(
+    ///     Barcode::encode("123", Symbology::Interleaved2Of5),
+    ///     Err(BarcodeRenderError::OddLength)
+    /// );
+    /// ```
+    pub fn encode(
+        digits: impl AsRef<str>,
+        symbology: Symbology,
+    ) -> Result<Self, BarcodeRenderError> {
+        let digits = digits.as_ref();
+        if let Some((position, character)) =
+            digits.char_indices().find(|(_, c)| !c.is_ascii_digit())
+        {
+            return Err(BarcodeRenderError::InvalidCharacter {
+                character,
+                position,
+            });
+        }
+
+        let modules = match symbology {
+            Symbology::Interleaved2Of5 => {
+                if digits.len() % 2 != 0 {
+                    return Err(BarcodeRenderError::OddLength);
+                }
+                interleaved::encode(digits.as_bytes())
+            }
+            Symbology::Code128 => code128::encode(digits.as_bytes()),
+        };
+        Ok(Barcode { modules })
+    }
+
+    /// Modules of the barcode without the quiet zone
+    pub fn modules(&self) -> &[bool] {
+        &self.modules
+    }
+
+    /// Width and height of the image in pixels
+    ///
+    /// Returns `Err(BarcodeRenderError::InvalidImageSize)` if the image is empty, a side is larger
+    /// than 2^31-1 pixels or the raster takes more than 2^31-1 bytes
+    ///
+    /// # Examples
+    /// ```
+    /// use rust_persian_tools::bill_barcode::{Barcode, BarcodeRenderError, ImageOptions, Symbology};
+    ///
+    /// let barcode = Barcode::encode("1234", Symbology::Interleaved2Of5).unwrap();
+    /// let options = ImageOptions { module_width: 2, height: 50, quiet_zone: 10 };
+    /// assert_eq!(barcode.image_size(&options), Ok((2 * (45 + 20), 50)));
+    /// assert_eq!(
+    ///     barcode.image_size(&ImageOptions { module_width: 0, ..options }),
+    ///     Err(BarcodeRenderError::InvalidImageSize)
+    /// );
+    /// assert_eq!(
+    ///     barcode.image_size(&ImageOptions { quiet_zone: u32::MAX, ..options }),
+    ///     Err(BarcodeRenderError::InvalidImageSize)
+    /// );
+    /// ```
+    pub fn image_size(&self, options: &ImageOptions) -> Result<(u32, u32), BarcodeRenderError> {
+        let width = u32::try_from(self.modules.len())
+            .ok()
+            .zip(options.quiet_zone.checked_mul(2))
+            .and_then(|(modules, quiet_zone)| modules.checked_add(quiet_zone))
+            .and_then(|modules| modules.checked_mul(options.module_width));
+
+        let height = options.height;
+        match width {
+            Some(width @ 1..=MAX_IMAGE_SIZE)
+                if (1..=MAX_IMAGE_SIZE).contains(&height)
+                    && raster_bytes(width, height) <= MAX_IMAGE_BYTES =>
+            {
+                Ok((width, height))
+            }
+            _ => Err(BarcodeRenderError::InvalidImageSize),
+        }
+    }
+
+    /// Renders the barcode as an SVG document, see [Barcode::image_size] for errors
+    pub fn to_svg(&self, options: &ImageOptions) -> Result<String, BarcodeRenderError> {
+        let (width, height) = self.image_size(options)?;
+        let mut svg = format!(
+            "<svg xmlns=\"http://www.w3.org/2000/svg\" width=\"{width}\" height=\"{height}\" viewBox=\"0 0 {width} {height}\">\
+             <rect width=\"{width}\" height=\"{height}\" fill=\"#fff\"/>"
+        );
+        for (start, len) in self.bars() {
+            let x = (start as u32 + options.quiet_zone) * options.module_width;
+            svg.push_str(&format!(
+                "<rect x=\"{x}\" width=\"{}\" height=\"{height}\"/>",
+                len as u32 * options.module_width
+            ));
+        }
+        svg.push_str("</svg>");
+        Ok(svg)
+    }
+
+    /// Renders the barcode as a binary PBM (P4) image, see [Barcode::image_size] for errors
+    pub fn to_pbm(&self, options: &ImageOptions) -> Result<Vec<u8>, BarcodeRenderError> {
+        let (width, height) = self.image_size(options)?;
+        let row = self.packed_row(options, width, true);
+        let mut pbm = format!("P4\n{width} {height}\n").into_bytes();
+        for _ in 0..height {
+            pbm.extend(&row);
+        }
+        Ok(pbm)
+    }
+
+    /// Renders the barcode as a 1-bit grayscale PNG image, see [Barcode::image_size] for errors
+    pub fn to_png(&self, options: &ImageOptions) -> Result<Vec<u8>, BarcodeRenderError> {
+        let (width, height) = self.image_size(options)?;
+        Ok(png::encode(
+            width,
+            height,
+            &self.packed_row(options, width, false),
+        ))
+    }
+
+    /// Start and length of the runs of dark modules
+    fn bars(&self) -> Vec<(usize, usize)> {
+        let mut bars: Vec<(usize, usize)> = Vec::new();
+        for (i, dark) in self.modules.iter().enumerate() {
+            match bars.last_mut() {
+                Some((start, len)) if *dark && *start + *len == i => *len += 1,
+                _ if *dark => bars.push((i, 1)),
+                _ => {}
+            }
+        }
+        bars
+    }
+
+    /// One row of pixels packed with the most significant bit first\
+    /// Bits of dark pixels are set if `dark_bit` is true and cleared otherwise
+    fn packed_row(&self, options: &ImageOptions, width: u32, dark_bit: bool) -> Vec<u8> {
+        let quiet = std::iter::repeat_n(false, options.quiet_zone as usize);
+        let pixels = quiet
+            .clone()
+            .chain(self.modules.iter().copied())
+            .chain(quiet)
+            .flat_map(|dark| std::iter::repeat_n(dark, options.module_width as usize));
+
+        let mut row = vec![0; (width as usize).div_ceil(8)];
+        for (i, dark) in pixels.enumerate() {
+            if dark == dark_bit {
+                row[i / 8] |= 0x80 >> (i % 8);
+            }
+        }
+        row
+    }
+}
+
+impl Bill {
+    /// Encodes the barcode of the bill, see [Barcode::encode]
+    pub fn barcode(&self, symbology: Symbology) -> Barcode {
+        // this is safe because the barcode of a bill is always 26 digits
+        Barcode::encode(self.to_string(), symbology).expect("bill barcode is 26 digits")
+    }
+}
+
+/// Bytes of a 1-bit raster with a leading byte in each row, as in PNG
+fn raster_bytes(width: u32, height: u32) -> u64 {
+    (width.div_ceil(8) as u64 + 1) * height as u64
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::str::FromStr;
+
+    fn barcode(modules: &str) -> Barcode {
+        Barcode {
+            modules: modules.chars().map(|c| c == '1').collect(),
+        }
+    }
+
+    #[test]
+    fn encode_test() {
+        assert_eq!(
+            Barcode::encode("12a4", Symbology::Code128),
+            Err(BarcodeRenderError::InvalidCharacter {
+                character: 'a',
+                position: 2
+            })
+        );
+        assert_eq!(
+            Barcode::encode("۱۲", Symbology::Code128),
+            Err(BarcodeRenderError::InvalidCharacter {
+                character: '۱',
+                position: 0
+            })
+        );
+        assert_eq!(
+            Barcode::encode("12۱2", Symbology::Code128),
+            Err(BarcodeRenderError::InvalidCharacter {
+                character: '۱',
+                position: 2
+            })
+        );
+
+        let bill = Bill::from_str("77483178001420000001770160").unwrap();
+        let itf = bill.barcode(Symbology::Interleaved2Of5);
+        assert_eq!(itf.modules().len(), 4 + 13 * 18 + 5);
+        let code128 = bill.barcode(Symbology::Code128);
+        assert_eq!(code128.modules().len(), (1 + 13 + 1) * 11 + 13);
+    }
+
+    #[test]
+    fn image_size_test() {
+        let options = ImageOptions {
+            module_width: 3,
+            height: 7,
+            quiet_zone: 1,
+        };
+        assert_eq!(barcode("101").image_size(&options), Ok((15, 7)));
+        // 3 bytes in each row
+        let height = (MAX_IMAGE_BYTES / 3) as u32;
+        assert_eq!(
+            barcode("101").image_size(&ImageOptions { height, ..options }),
+            Ok((15, height))
+        );
+
+        for options in [
+            ImageOptions {
+                module_width: 0,
+                ..options
+            },
+            ImageOptions {
+                height: 0,
+                ..options
+            },
+            ImageOptions {
+                module_width: u32::MAX,
+                ..options
+            },
+            ImageOptions {
+                quiet_zone: u32::MAX / 2,
+                ..options
+            },
+            ImageOptions {
+                height: MAX_IMAGE_SIZE + 1,
+                ..options
+            },
+            ImageOptions {
+                height: MAX_IMAGE_SIZE,
+                ..options
+            },
+            ImageOptions {
+                module_width: MAX_IMAGE_SIZE / 15,
+                height: 30,
+                ..options
+            },
+        ] {
+            let barcode = barcode("101");
+            assert_eq!(
+                barcode.image_size(&options),
+                Err(BarcodeRenderError::InvalidImageSize)
+            );
+            assert_eq!(
+                barcode.to_png(&options),
+                Err(BarcodeRenderError::InvalidImageSize)
+            );
+        }
+    }
+
+    #[test]
+    fn svg_test() {
+        let options = ImageOptions {
+            module_width: 2,
+            height: 10,
+            quiet_zone: 1,
+        };
+        assert_eq!(
+            barcode("1101").to_svg(&options).unwrap(),
+            "<svg xmlns=\"http://www.w3.org/2000/svg\" width=\"12\" height=\"10\" viewBox=\"0 0 12 10\">\
+             <rect width=\"12\" height=\"10\" fill=\"#fff\"/>\
+             <rect x=\"2\" width=\"4\" height=\"10\"/>\
+             <rect x=\"8\" width=\"2\" height=\"10\"/></svg>"
+        );
+    }
+
+    #[test]
+    fn pbm_test() {
+        let options = ImageOptions {
+            module_width: 1,
+            height: 2,
+            quiet_zone: 2,
+        };
+        let pbm = barcode("1100101").to_pbm(&options).unwrap();
+        let mut expected = b"P4\n11 2\n".to_vec();
+        expected.extend([0b0011_0010, 0b1000_0000, 0b0011_0010, 0b1000_0000]);
+        assert_eq!(pbm, expected);
+    }
+
+    #[test]
+    fn png_test() {
+        let options = ImageOptions {
+            module_width: 3,
+            height: 1,
+            quiet_zone: 0,
+        };
+        let png = barcode("101").to_png(&options).unwrap();
+        // IHDR
+        assert_eq!(&png[16..24], &[0, 0, 0, 9, 0, 0, 0, 1]);
+        // first stored block of IDAT: filter byte and the row
+        let idat = 8 + 25;
+        assert_eq!(&png[idat + 4..idat + 8], b"IDAT");
+        assert_eq!(&png[idat + 15..idat + 18], &[0, 0b0001_1100, 0]);
+    }
+}
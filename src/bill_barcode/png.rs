@@ -0,0 +1,158 @@
+//! Minimal PNG writer for 1-bit grayscale images\
+//! Image data is stored in uncompressed deflate blocks, so no compression library is needed
+
+const SIGNATURE: [u8; 8] = [0x89, b'P', b'N', b'G', b'\r', b'\n', 0x1a, b'\n'];
+/// Largest length of a stored deflate block
+const MAX_BLOCK: usize = 0xffff;
+/// Largest length of chunk data, PNG doesn't allow larger chunks
+const MAX_CHUNK: usize = i32::MAX as usize;
+
+pub(super) fn crc32(data: &[u8]) -> u32 {
+    let mut crc = !0u32;
+    for byte in data {
+        crc ^= *byte as u32;
+        for _ in 0..8 {
+            crc = if crc & 1 == 1 {
+                (crc >> 1) ^ 0xedb8_8320
+            } else {
+                crc >> 1
+            };
+        }
+    }
+    !crc
+}
+
+pub(super) fn adler32(data: &[u8]) -> u32 {
+    let (mut a, mut b) = (1u32, 0u32);
+    for byte in data {
+        a = (a + *byte as u32) % 65521;
+        b = (b + a) % 65521;
+    }
+    (b << 16) | a
+}
+
+/// zlib stream of `data` made of stored deflate blocks
+fn zlib_stored(data: &[u8]) -> Vec<u8> {
+    let blocks = data.len().div_ceil(MAX_BLOCK).max(1);
+    let mut stream = Vec::with_capacity(data.len() + blocks * 5 + 6);
+    // deflate with a 32K window and no preset dictionary
+    stream.extend([0x78, 0x01]);
+
+    let mut chunks = data.chunks(MAX_BLOCK).peekable();
+    if chunks.peek().is_none() {
+        stream.extend([1, 0, 0, 0xff, 0xff]);
+    }
+    while let Some(chunk) = chunks.next() {
+        let is_final = chunks.peek().is_none();
+        let len = chunk.len() as u16;
+        stream.push(is_final as u8);
+        stream.extend(len.to_le_bytes());
+        stream.extend((!len).to_le_bytes());
+        stream.extend(chunk);
+    }
+
+    stream.extend(adler32(data).to_be_bytes());
+    stream
+}
+
+/// `data` must not be longer than [MAX_CHUNK]
+fn push_chunk(png: &mut Vec<u8>, kind: &[u8; 4], data: &[u8]) {
+    debug_assert!(data.len() <= MAX_CHUNK);
+    png.extend((data.len() as u32).to_be_bytes());
+    let start = png.len();
+    png.extend(kind);
+    png.extend(data);
+    let crc = crc32(&png[start..]);
+    png.extend(crc.to_be_bytes());
+}
+
+/// Image data split into IDAT chunks of at most `max_len` bytes
+fn push_image_data(png: &mut Vec<u8>, stream: &[u8], max_len: usize) {
+    for data in stream.chunks(max_len) {
+        push_chunk(png, b"IDAT", data);
+    }
+}
+
+/// PNG of a 1-bit grayscale image where every row is `row`\
+/// `row` is packed with the most significant bit first and a set bit is white
+pub(super) fn encode(width: u32, height: u32, row: &[u8]) -> Vec<u8> {
+    let mut header = Vec::with_capacity(13);
+    header.extend(width.to_be_bytes());
+    header.extend(height.to_be_bytes());
+    // bit depth 1, grayscale, deflate, adaptive filtering, no interlace
+    header.extend([1, 0, 0, 0, 0]);
+
+    let mut data = Vec::with_capacity((row.len() + 1) * height as usize);
+    for _ in 0..height {
+        // filter type: none
+        data.push(0);
+        data.extend(row);
+    }
+
+    let mut png = Vec::new();
+    png.extend(SIGNATURE);
+    push_chunk(&mut png, b"IHDR", &header);
+    push_image_data(&mut png, &zlib_stored(&data), MAX_CHUNK);
+    push_chunk(&mut png, b"IEND", &[]);
+    png
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn checksums_test() {
+        assert_eq!(crc32(b""), 0);
+        assert_eq!(crc32(b"123456789"), 0xcbf4_3926);
+        assert_eq!(crc32(b"IEND"), 0xae42_6082);
+        assert_eq!(adler32(b""), 1);
+        assert_eq!(adler32(b"Wikipedia"), 0x11e6_0398);
+    }
+
+    #[test]
+    fn zlib_stored_test() {
+        assert_eq!(
+            zlib_stored(b""),
+            [0x78, 0x01, 1, 0, 0, 0xff, 0xff, 0, 0, 0, 1]
+        );
+        assert_eq!(
+            zlib_stored(b"ab"),
+            [0x78, 0x01, 1, 2, 0, 0xfd, 0xff, b'a', b'b', 0x01, 0x26, 0, 0xc4]
+        );
+
+        let data = vec![7; MAX_BLOCK + 1];
+        let stream = zlib_stored(&data);
+        assert_eq!(stream.len(), 2 + 5 + MAX_BLOCK + 5 + 1 + 4);
+        assert_eq!(stream[2], 0);
+        assert_eq!(stream[2 + 5 + MAX_BLOCK], 1);
+    }
+
+    #[test]
+    fn png_test() {
+        let png = encode(3, 2, &[0b1010_0000]);
+        assert_eq!(&png[..8], &SIGNATURE);
+        assert_eq!(&png[12..16], b"IHDR");
+        assert_eq!(&png[16..24], &[0, 0, 0, 3, 0, 0, 0, 2]);
+        assert!(png.ends_with(&[0, 0, 0, 0, b'I', b'E', b'N', b'D', 0xae, 0x42, 0x60, 0x82]));
+    }
+
+    #[test]
+    fn image_data_test() {
+        let mut png = Vec::new();
+        push_image_data(&mut png, b"abcde", 2);
+        let mut chunks = Vec::new();
+        let mut rest = png.as_slice();
+        while !rest.is_empty() {
+            let len = u32::from_be_bytes(rest[..4].try_into().unwrap()) as usize;
+            assert_eq!(&rest[4..8], b"IDAT");
+            assert_eq!(
+                crc32(&rest[4..8 + len]).to_be_bytes(),
+                rest[8 + len..12 + len]
+            );
+            chunks.push(&rest[8..8 + len]);
+            rest = &rest[12 + len..];
+        }
+        assert_eq!(chunks, [&b"ab"[..], b"cd", b"e"]);
+    }
+}
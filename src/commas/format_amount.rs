@@ -0,0 +1,217 @@
+use crate::digits::{en_to_language, Language};
+
+use super::add_commas::add_commas;
+
+/// Thousands and decimal separators
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(PartialEq, Eq, Debug, Clone, Copy, Hash, Default)]
+pub enum Separators {
+    /// "1,234.5"
+    #[default]
+    Latin,
+    /// "1٬234٫5"
+    Persian,
+}
+
+/// Placement of the negative sign
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(PartialEq, Eq, Debug, Clone, Copy, Hash, Default)]
+pub enum SignPlacement {
+    /// "-1,000"
+    #[default]
+    Leading,
+    /// "1,000-", which is shown as "-1,000" in right-to-left text
+    Trailing,
+    /// "-1,000" with a left-to-right mark before the sign,
+    /// so the sign stays on the left of the number in right-to-left text
+    LeftToRightMark,
+}
+
+/// Currency name that is written after the amount
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(PartialEq, Eq, Debug, Clone, Copy, Hash)]
+pub enum CurrencySuffix {
+    Rial,
+    Toman,
+}
+
+impl CurrencySuffix {
+    pub fn name(&self) -> &'static str {
+        match self {
+            CurrencySuffix::Rial => "ریال",
+            CurrencySuffix::Toman => "تومان",
+        }
+    }
+}
+
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(PartialEq, Eq, Debug, Clone, Copy, Hash)]
+pub struct AmountOptions {
+    pub separators: Separators,
+    pub digits: Language,
+    /// Fixed number of digits after the decimal separator, [None] keeps the number as it is
+    pub decimals: Option<usize>,
+    pub currency: Option<CurrencySuffix>,
+    pub sign: SignPlacement,
+}
+
+impl Default for AmountOptions {
+    fn default() -> Self {
+        AmountOptions {
+            separators: Separators::default(),
+            digits: Language::English,
+            decimals: None,
+            currency: None,
+            sign: SignPlacement::default(),
+        }
+    }
+}
+
+/// Numbers that can be formatted by [format_amount]
+pub trait Amount {
+    /// Plain representation of the number such as "-1234.50"
+    fn to_plain_string(&self, decimals: Option<usize>) -> String;
+}
+
+macro_rules! impl_amount_for_integers {
+    ($($t:ty),*) => {
+        $(
+            impl Amount for $t {
+                fn to_plain_string(&self, decimals: Option<usize>) -> String {
+                    match decimals {
+                        Some(decimals) if decimals > 0 => {
+                            format!("{}.{}", self, "0".repeat(decimals))
+                        }
+                        _ => self.to_string(),
+                    }
+                }
+            }
+        )*
+    };
+}
+
+impl_amount_for_integers!(i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize);
+
+macro_rules! impl_amount_for_floats {
+    ($($t:ty),*) => {
+        $(
+            impl Amount for $t {
+                fn to_plain_string(&self, decimals: Option<usize>) -> String {
+                    match decimals {
+                        Some(decimals) => format!("{:.*}", decimals, self),
+                        None => self.to_string(),
+                    }
+                }
+            }
+        )*
+    };
+}
+
+impl_amount_for_floats!(f32, f64);
+
+/// Format an amount with thousands separators, digits script, currency and sign placement\
+/// NaN and infinite floats are returned as they are.
+/// ```
+/// use rust_persian_tools::commas::format_amount::{
+///     format_amount, AmountOptions, CurrencySuffix, Separators, SignPlacement,
+/// };
+/// use rust_persian_tools::digits::Language;
+///
+/// assert_eq!(format_amount(1234567, &AmountOptions::default()), "1,234,567");
+///
+/// let options = AmountOptions {
+///     separators: Separators::Persian,
+///     digits: Language::Persian,
+///     decimals: Some(2),
+///     currency: Some(CurrencySuffix::Toman),
+///     sign: SignPlacement::Trailing,
+/// };
+/// assert_eq!(format_amount(-1234.5, &options), "۱٬۲۳۴٫۵۰- تومان");
+/// ```
+pub fn format_amount(amount: impl Amount, options: &AmountOptions) -> String {
+    let plain = amount.to_plain_string(options.decimals);
+    let (is_negative, unsigned) = match plain.strip_prefix('-') {
+        Some(unsigned) => (true, unsigned),
+        None => (false, plain.as_str()),
+    };
+    if !unsigned.starts_with(|c: char| c.is_ascii_digit()) {
+        return plain;
+    }
+
+    let (integer, fraction) = match unsigned.split_once('.') {
+        Some((integer, fraction)) => (integer, Some(fraction)),
+        None => (unsigned, None),
+    };
+    // "-0.00" is zero
+    let is_negative = is_negative && unsigned.bytes().any(|b| (b'1'..=b'9').contains(&b));
+
+    let mut result = add_commas(integer);
+    if options.separators == Separators::Persian {
+        result = result.replace(',', "٬");
+    }
+    if let Some(fraction) = fraction {
+        result.push(match options.separators {
+            Separators::Latin => '.',
+            Separators::Persian => '٫',
+        });
+        result.push_str(fraction);
+    }
+    let mut result = en_to_language(result, options.digits);
+
+    if is_negative {
+        result = match options.sign {
+            SignPlacement::Leading => format!("-{result}"),
+            SignPlacement::Trailing => format!("{result}-"),
+            SignPlacement::LeftToRightMark => format!("\u{200e}-{result}"),
+        };
+    }
+    if let Some(currency) = options.currency {
+        result = format!("{} {}", result, currency.name());
+    }
+
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn format_amount_test() {
+        let options = AmountOptions::default();
+        assert_eq!(format_amount(0, &options), "0");
+        assert_eq!(format_amount(-1000_i16, &options), "-1,000");
+        assert_eq!(format_amount(u128::MAX, &options).len(), 51);
+        assert_eq!(format_amount(1234.5_f32, &options), "1,234.5");
+        assert_eq!(format_amount(f64::NAN, &options), "NaN");
+        assert_eq!(format_amount(f64::NEG_INFINITY, &options), "-inf");
+
+        let options = AmountOptions {
+            decimals: Some(2),
+            ..Default::default()
+        };
+        assert_eq!(format_amount(12, &options), "12.00");
+        assert_eq!(format_amount(0.125, &options), "0.12");
+        assert_eq!(format_amount(-0.001, &options), "0.00");
+        assert_eq!(format_amount(-9999.999, &options), "-10,000.00");
+    }
+
+    #[test]
+    fn format_amount_options_test() {
+        let options = AmountOptions {
+            digits: Language::Arabic,
+            currency: Some(CurrencySuffix::Rial),
+            sign: SignPlacement::LeftToRightMark,
+            ..Default::default()
+        };
+        assert_eq!(format_amount(-25000, &options), "\u{200e}-٢٥,٠٠٠ ریال");
+
+        let options = AmountOptions {
+            separators: Separators::Persian,
+            decimals: Some(0),
+            ..Default::default()
+        };
+        assert_eq!(format_amount(1500.7, &options), "1٬501");
+        assert_eq!(format_amount(1500_u64, &options), "1٬500");
+    }
+}
@@ -1,7 +1,8 @@
 pub mod add_commas;
+pub mod format_amount;
 pub mod remove_commas;
 
 pub use {
-    add_commas::add_commas, add_commas::add_commas_mut, remove_commas::remove_commas,
-    remove_commas::remove_commas_mut,
+    add_commas::add_commas, add_commas::add_commas_mut, format_amount::format_amount,
+    remove_commas::remove_commas, remove_commas::remove_commas_mut,
 };
@@ -16,6 +16,31 @@
 use std::borrow::Cow;
 use std::ops::Range;
 
+/// Digits script of a generated text.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(PartialEq, Eq, Debug, Clone, Copy, Hash)]
+pub enum Language {
+    Arabic,
+    Persian,
+    English,
+}
+
+/// Convert English digits to digits of the given [Language].
+/// ```rust
+/// use rust_persian_tools::digits::{en_to_language, Language};
+///
+/// assert_eq!(en_to_language("1403/07/26", Language::Persian), "۱۴۰۳/۰۷/۲۶");
+/// assert_eq!(en_to_language("1403/07/26", Language::Arabic), "١٤٠٣/٠٧/٢٦");
+/// assert_eq!(en_to_language("1403/07/26", Language::English), "1403/07/26");
+/// ```
+pub fn en_to_language(input: impl AsRef<str>, language: Language) -> String {
+    match language {
+        Language::Arabic => en_to_ar(input),
+        Language::Persian => en_to_fa(input),
+        Language::English => input.as_ref().to_string(),
+    }
+}
+
 /// Convert Persian digits to English digits.
 pub fn fa_to_en(input: impl AsRef<str>) -> String {
     // UTF-8 code points for Persian numbers: 1776..1785
@@ -0,0 +1,104 @@
+//! Arithmetical (tabular) Islamic calendar with the common 30-year cycle
+//! (leap years 2, 5, 7, 10, 13, 16, 18, 21, 24, 26 and 29).
+
+use chrono::{Days, NaiveDate};
+
+pub(super) const MIN_YEAR: i32 = 1;
+pub(super) const MAX_YEAR: i32 = 9999;
+
+/// 1 Muharram 1 (16 July 622 Julian)
+fn epoch() -> NaiveDate {
+    NaiveDate::from_ymd_opt(622, 7, 19).expect("valid epoch")
+}
+
+pub(super) fn is_leap_year(year: i32) -> bool {
+    (14 + 11 * year).rem_euclid(30) < 11
+}
+
+pub(super) fn month_length(year: i32, month: u8) -> u8 {
+    if month % 2 == 1 || (month == 12 && is_leap_year(year)) {
+        30
+    } else {
+        29
+    }
+}
+
+fn days_before_year(year: i32) -> i64 {
+    (year as i64 - 1) * 354 + (3 + 11 * year as i64).div_euclid(30)
+}
+
+fn days_before_month(month: u8) -> i64 {
+    (59 * (month as i64 - 1) + 1) / 2
+}
+
+/// `day` must be valid in the month
+pub(super) fn to_gregorian(year: i32, month: u8, day: u8) -> Option<NaiveDate> {
+    let days = days_before_year(year) + days_before_month(month) + day as i64 - 1;
+
+    epoch().checked_add_days(Days::new(u64::try_from(days).ok()?))
+}
+
+pub(super) fn from_gregorian(date: NaiveDate) -> Option<(i32, u8, u8)> {
+    let days = (date - epoch()).num_days();
+    if days < 0 {
+        return None;
+    }
+
+    let mut year = ((30 * days + 10646) / 10631) as i32;
+    while days_before_year(year) > days {
+        year -= 1;
+    }
+    while days_before_year(year + 1) <= days {
+        year += 1;
+    }
+    if !(MIN_YEAR..=MAX_YEAR).contains(&year) {
+        return None;
+    }
+
+    let day_of_year = days - days_before_year(year);
+    let month = (1..=12)
+        .rev()
+        .find(|month| days_before_month(*month) <= day_of_year)?;
+    let day = (day_of_year - days_before_month(month) + 1) as u8;
+
+    Some((year, month, day))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::Datelike;
+
+    #[test]
+    fn conversion_test() {
+        assert_eq!(from_gregorian(epoch()), Some((1, 1, 1)));
+        assert_eq!(to_gregorian(1, 1, 1), Some(epoch()));
+        assert_eq!(
+            from_gregorian(NaiveDate::from_ymd_opt(622, 7, 18).unwrap()),
+            None
+        );
+
+        let date = NaiveDate::from_ymd_opt(2024, 7, 8).unwrap();
+        assert_eq!(from_gregorian(date), Some((1446, 1, 1)));
+        assert_eq!(to_gregorian(1446, 1, 1), Some(date));
+    }
+
+    #[test]
+    fn round_trip_test() {
+        let mut date = NaiveDate::from_ymd_opt(1900, 1, 1).unwrap();
+        while date.year() < 2100 {
+            let (year, month, day) = from_gregorian(date).unwrap();
+            assert!(day <= month_length(year, month));
+            assert_eq!(to_gregorian(year, month, day), Some(date));
+            date = date.succ_opt().unwrap();
+        }
+    }
+
+    #[test]
+    fn leap_year_test() {
+        let leap_years = (1..=30)
+            .filter(|year| is_leap_year(*year))
+            .collect::<Vec<_>>();
+        assert_eq!(leap_years, [2, 5, 7, 10, 13, 16, 18, 21, 24, 26, 29]);
+    }
+}
@@ -0,0 +1,392 @@
+//! Hijri Qamari (Islamic lunar) calendar (`hijri` Cargo feature).
+//!
+//! Months start with sighting of the new moon, so the calendar can not be fully computed.
+//! Conversions use the month starts officially observed in Iran from a bundled table
+//! (Hijri years 1443 to 1450) and the arithmetical (tabular) calendar out of it,
+//! which may differ by one or two days from the observed calendar.
+//!
+//! See "Update database" section of `Contributing.md` if you find a wrong date.
+//!
+//! #### Example
+//! ```rust
+//! use rust_persian_tools::hijri::HijriDate;
+//! use chrono::NaiveDate;
+//!
+//! let eid_fitr = NaiveDate::from_ymd_opt(2024, 4, 10).unwrap();
+//! let date = HijriDate::from_gregorian(eid_fitr).unwrap();
+//! assert_eq!(date, HijriDate::new(1445, 10, 1).unwrap());
+//! assert_eq!(date.month_name(), "شوال");
+//! assert_eq!(date.to_string(), "1445/10/01");
+//! assert_eq!(date.to_gregorian(), eid_fitr);
+//!
+//! let ashura: HijriDate = "۱۴۴۶/۰۱/۱۰".parse().unwrap();
+//! assert_eq!(NaiveDate::from(ashura), NaiveDate::from_ymd_opt(2024, 7, 16).unwrap());
+//! ```
+
+mod algorithm;
+pub mod errors;
+mod observed;
+
+use std::fmt;
+use std::str::FromStr;
+
+use chrono::{Datelike, Days, NaiveDate, Weekday};
+
+use self::algorithm::{MAX_YEAR, MIN_YEAR};
+pub use self::errors::HijriError;
+use crate::digits::{DigitsAr2En, DigitsFa2En};
+
+const MONTH_NAMES: [&str; 12] = [
+    "محرم",
+    "صفر",
+    "ربیع\u{200c}الاول",
+    "ربیع\u{200c}الثانی",
+    "جمادی\u{200c}الاول",
+    "جمادی\u{200c}الثانی",
+    "رجب",
+    "شعبان",
+    "رمضان",
+    "شوال",
+    "ذی\u{200c}القعده",
+    "ذی\u{200c}الحجه",
+];
+
+const MONTH_NAMES_AR: [&str; 12] = [
+    "محرم",
+    "صفر",
+    "ربيع الأول",
+    "ربيع الآخر",
+    "جمادى الأولى",
+    "جمادى الآخرة",
+    "رجب",
+    "شعبان",
+    "رمضان",
+    "شوال",
+    "ذو القعدة",
+    "ذو الحجة",
+];
+
+/// Returns number of days (29 or 30) in the given month of a Hijri year
+/// or [None] if month is not in `1..=12`.
+/// ```
+/// use rust_persian_tools::hijri::days_in_month;
+///
+/// assert_eq!(days_in_month(1445, 12), Some(29));
+/// assert_eq!(days_in_month(1446, 2), Some(30));
+/// assert_eq!(days_in_month(1446, 13), None);
+/// ```
+pub fn days_in_month(year: i32, month: u8) -> Option<u8> {
+    if !(1..=12).contains(&month) {
+        return None;
+    }
+
+    observed::month_length(year, month).or(Some(algorithm::month_length(year, month)))
+}
+
+/// A date in Hijri Qamari calendar.\
+/// The struct is always valid; use [HijriDate::new] or conversion from [NaiveDate] to build one.\
+/// With `serde` Cargo feature it is deserialized through [HijriDate::new], so invalid dates are rejected.
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(try_from = "HijriDateFields")
+)]
+#[derive(PartialEq, Eq, PartialOrd, Ord, Debug, Clone, Copy, Hash)]
+pub struct HijriDate {
+    year: i32,
+    month: u8,
+    day: u8,
+}
+
+/// Fields of [HijriDate] before validation
+#[cfg(feature = "serde")]
+#[derive(serde::Deserialize)]
+struct HijriDateFields {
+    year: i32,
+    month: u8,
+    day: u8,
+}
+
+#[cfg(feature = "serde")]
+impl TryFrom<HijriDateFields> for HijriDate {
+    type Error = HijriError;
+
+    fn try_from(fields: HijriDateFields) -> Result<Self, Self::Error> {
+        HijriDate::new(fields.year, fields.month, fields.day)
+    }
+}
+
+impl HijriDate {
+    /// Builds a Hijri date and checks its validity.
+    /// ```
+    /// use rust_persian_tools::hijri::{HijriDate, HijriError};
+    ///
+    /// assert!(HijriDate::new(1446, 2, 30).is_ok());
+    /// assert_eq!(HijriDate::new(1445, 12, 30), Err(HijriError::InvalidDay(30)));
+    /// assert_eq!(HijriDate::new(1445, 13, 1), Err(HijriError::InvalidMonth(13)));
+    /// ```
+    pub fn new(year: i32, month: u8, day: u8) -> Result<Self, HijriError> {
+        if !(MIN_YEAR..=MAX_YEAR).contains(&year) {
+            return Err(HijriError::InvalidYear(year));
+        }
+        let max_day = days_in_month(year, month).ok_or(HijriError::InvalidMonth(month))?;
+        if day == 0 || day > max_day {
+            return Err(HijriError::InvalidDay(day));
+        }
+        Ok(HijriDate { year, month, day })
+    }
+
+    /// Converts a Gregorian date to Hijri.
+    /// ```
+    /// use rust_persian_tools::hijri::{HijriDate, HijriError};
+    /// use chrono::NaiveDate;
+    ///
+    /// let date = HijriDate::from_gregorian(NaiveDate::from_ymd_opt(2000, 1, 1).unwrap()).unwrap();
+    /// assert_eq!(date, HijriDate::new(1420, 9, 24).unwrap());
+    /// assert_eq!(
+    ///     HijriDate::from_gregorian(NaiveDate::from_ymd_opt(600, 1, 1).unwrap()),
+    ///     Err(HijriError::OutOfRange)
+    /// );
+    /// ```
+    pub fn from_gregorian(date: NaiveDate) -> Result<Self, HijriError> {
+        let (year, month, day) = observed::from_gregorian(date)
+            .or_else(|| algorithm::from_gregorian(date))
+            .ok_or(HijriError::OutOfRange)?;
+        Ok(HijriDate { year, month, day })
+    }
+
+    /// Converts the date to Gregorian.
+    /// ```
+    /// use rust_persian_tools::hijri::HijriDate;
+    /// use chrono::NaiveDate;
+    ///
+    /// let date = HijriDate::new(1446, 12, 10).unwrap();
+    /// assert_eq!(date.to_gregorian(), NaiveDate::from_ymd_opt(2025, 6, 6).unwrap());
+    /// ```
+    pub fn to_gregorian(&self) -> NaiveDate {
+        // this is safe because the date is validated on creation
+        match observed::month_start(self.year, self.month) {
+            Some(start) => start.checked_add_days(Days::new(self.day as u64 - 1)),
+            None => algorithm::to_gregorian(self.year, self.month, self.day),
+        }
+        .expect("valid hijri date")
+    }
+
+    pub fn year(&self) -> i32 {
+        self.year
+    }
+
+    pub fn month(&self) -> u8 {
+        self.month
+    }
+
+    pub fn day(&self) -> u8 {
+        self.day
+    }
+
+    pub fn weekday(&self) -> Weekday {
+        self.to_gregorian().weekday()
+    }
+
+    pub fn days_in_month(&self) -> u8 {
+        // this is safe because month is validated on creation
+        days_in_month(self.year, self.month).expect("valid month")
+    }
+
+    /// Adds (or subtracts if negative) the given number of days.\
+    /// Returns [None] if the result is out of the supported range.
+    /// ```
+    /// use rust_persian_tools::hijri::HijriDate;
+    ///
+    /// let date = HijriDate::new(1445, 12, 29).unwrap();
+    /// assert_eq!(date.checked_add_days(1), Some(HijriDate::new(1446, 1, 1).unwrap()));
+    /// assert_eq!(date.checked_add_days(-29), Some(HijriDate::new(1445, 11, 30).unwrap()));
+    /// ```
+    pub fn checked_add_days(self, days: i64) -> Option<Self> {
+        let date = self
+            .to_gregorian()
+            .checked_add_signed(chrono::Duration::try_days(days)?)?;
+        HijriDate::from_gregorian(date).ok()
+    }
+
+    /// Persian name of the month, e.g. "محرم"
+    pub fn month_name(&self) -> &'static str {
+        MONTH_NAMES[self.month as usize - 1]
+    }
+
+    /// Arabic name of the month, e.g. "ربيع الأول"
+    pub fn month_name_ar(&self) -> &'static str {
+        MONTH_NAMES_AR[self.month as usize - 1]
+    }
+}
+
+impl FromStr for HijriDate {
+    type Err = HijriError;
+
+    /// Parses `YYYY/MM/DD` or `YYYY-MM-DD` with any kind of digits
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let s = s.trim().digits_fa_to_en().digits_ar_to_en();
+        let separator = if s.contains('/') { '/' } else { '-' };
+
+        let parts = s.split(separator).collect::<Vec<_>>();
+        let [year, month, day] = parts[..] else {
+            return Err(HijriError::InvalidFormat);
+        };
+        if [year, month, day]
+            .iter()
+            .any(|part| part.is_empty() || !part.bytes().all(|b| b.is_ascii_digit()))
+        {
+            return Err(HijriError::InvalidFormat);
+        }
+
+        HijriDate::new(
+            year.parse().map_err(|_| HijriError::InvalidFormat)?,
+            month.parse().map_err(|_| HijriError::InvalidFormat)?,
+            day.parse().map_err(|_| HijriError::InvalidFormat)?,
+        )
+    }
+}
+
+impl TryFrom<NaiveDate> for HijriDate {
+    type Error = HijriError;
+
+    fn try_from(date: NaiveDate) -> Result<Self, Self::Error> {
+        HijriDate::from_gregorian(date)
+    }
+}
+
+impl From<HijriDate> for NaiveDate {
+    fn from(date: HijriDate) -> Self {
+        date.to_gregorian()
+    }
+}
+
+impl fmt::Display for HijriDate {
+    /// Formats date as `YYYY/MM/DD`
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:04}/{:02}/{:02}", self.year, self.month, self.day)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn gregorian(year: i32, month: u32, day: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(year, month, day).unwrap()
+    }
+
+    #[test]
+    fn observed_test() {
+        let cases = [
+            (gregorian(2023, 4, 22), (1444, 10, 1)),
+            (gregorian(2023, 7, 19), (1445, 1, 1)),
+            (gregorian(2024, 4, 10), (1445, 10, 1)),
+            (gregorian(2024, 7, 7), (1446, 1, 1)),
+            (gregorian(2025, 3, 31), (1446, 10, 1)),
+            (gregorian(2025, 6, 27), (1447, 1, 1)),
+        ];
+        for (date, (year, month, day)) in cases {
+            let hijri = HijriDate::new(year, month, day).unwrap();
+            assert_eq!(HijriDate::from_gregorian(date), Ok(hijri));
+            assert_eq!(hijri.to_gregorian(), date);
+        }
+    }
+
+    #[test]
+    fn tabular_test() {
+        assert_eq!(
+            HijriDate::from_gregorian(gregorian(1979, 2, 11)),
+            HijriDate::new(1399, 3, 13)
+        );
+        assert_eq!(
+            HijriDate::new(1, 1, 1).unwrap().to_gregorian(),
+            gregorian(622, 7, 19)
+        );
+    }
+
+    #[test]
+    fn round_trip_test() {
+        let mut date = gregorian(1950, 1, 1);
+        while date.year() < 2050 {
+            let hijri = HijriDate::from_gregorian(date).unwrap();
+            assert_eq!(hijri.to_gregorian(), date);
+            assert_eq!(
+                HijriDate::new(hijri.year(), hijri.month(), hijri.day()),
+                Ok(hijri)
+            );
+
+            let next = hijri.checked_add_days(1).unwrap();
+            if hijri.day() == hijri.days_in_month() {
+                assert_eq!(next.day(), 1);
+            } else {
+                assert_eq!(next.day(), hijri.day() + 1);
+            }
+
+            date = date.succ_opt().unwrap();
+        }
+    }
+
+    #[test]
+    fn from_str_test() {
+        assert_eq!("1446-01-10".parse(), HijriDate::new(1446, 1, 10));
+        assert_eq!("١٤٤٦/١/١٠".parse(), HijriDate::new(1446, 1, 10));
+        assert_eq!(
+            "1446/01".parse::<HijriDate>(),
+            Err(HijriError::InvalidFormat)
+        );
+        assert_eq!(
+            "1446/01/x".parse::<HijriDate>(),
+            Err(HijriError::InvalidFormat)
+        );
+        assert_eq!(
+            "1446/01/31".parse::<HijriDate>(),
+            Err(HijriError::InvalidDay(31))
+        );
+        assert_eq!(
+            "1446/300/1".parse::<HijriDate>(),
+            Err(HijriError::InvalidFormat)
+        );
+        assert_eq!(
+            "0/1/1".parse::<HijriDate>(),
+            Err(HijriError::InvalidYear(0))
+        );
+    }
+
+    #[test]
+    fn names_test() {
+        let date = HijriDate::new(1446, 3, 17).unwrap();
+        assert_eq!(date.month_name(), "ربیع\u{200c}الاول");
+        assert_eq!(date.month_name_ar(), "ربيع الأول");
+        assert_eq!(date.weekday(), Weekday::Sat);
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn serde_test() {
+        let date = HijriDate::new(1446, 2, 30).unwrap();
+        let json_str = serde_json::to_string(&date).unwrap();
+        assert_eq!(json_str, "{\"year\":1446,\"month\":2,\"day\":30}");
+        assert_eq!(serde_json::from_str::<HijriDate>(&json_str).unwrap(), date);
+
+        for invalid in [
+            "{\"year\":1446,\"month\":13,\"day\":1}",
+            "{\"year\":1446,\"month\":0,\"day\":1}",
+            "{\"year\":1445,\"month\":12,\"day\":30}",
+            "{\"year\":1446,\"month\":1,\"day\":0}",
+            "{\"year\":0,\"month\":1,\"day\":1}",
+        ] {
+            assert!(
+                serde_json::from_str::<HijriDate>(invalid).is_err(),
+                "{invalid}"
+            );
+        }
+        assert_eq!(
+            serde_json::from_str::<HijriDate>("{\"year\":1445,\"month\":12,\"day\":30}")
+                .err()
+                .unwrap()
+                .to_string(),
+            HijriError::InvalidDay(30).to_string()
+        );
+    }
+}
@@ -0,0 +1,242 @@
+//! Bundled table of Hijri Qamari month starts as officially observed in Iran.\
+//! Dates out of this table fall back to the arithmetical calendar.
+
+use chrono::NaiveDate;
+
+/// First Hijri year of [MONTH_STARTS]
+const FIRST_YEAR: i32 = 1443;
+
+/// Gregorian date of the first day of each month, for Hijri years 1443 to 1450.
+///
+/// Month starts are the arithmetical calendar corrected by the starts that were
+/// officially observed in Iran. Months after the last published official calendar are
+/// predictions and may be off by one day, so this table should be updated every year.
+const MONTH_STARTS: [[(i32, u32, u32); 12]; 8] = [
+    // 1443
+    [
+        (2021, 8, 10),
+        (2021, 9, 9),
+        (2021, 10, 8),
+        (2021, 11, 7),
+        (2021, 12, 6),
+        (2022, 1, 5),
+        (2022, 2, 3),
+        (2022, 3, 5),
+        (2022, 4, 3),
+        (2022, 5, 3),
+        (2022, 6, 1),
+        (2022, 7, 1),
+    ],
+    // 1444
+    [
+        (2022, 7, 30),
+        (2022, 8, 29),
+        (2022, 9, 27),
+        (2022, 10, 27),
+        (2022, 11, 25),
+        (2022, 12, 25),
+        (2023, 1, 23),
+        (2023, 2, 22),
+        (2023, 3, 23),
+        (2023, 4, 22),
+        (2023, 5, 21),
+        (2023, 6, 20),
+    ],
+    // 1445
+    [
+        (2023, 7, 19),
+        (2023, 8, 18),
+        (2023, 9, 16),
+        (2023, 10, 16),
+        (2023, 11, 14),
+        (2023, 12, 14),
+        (2024, 1, 12),
+        (2024, 2, 11),
+        (2024, 3, 12),
+        (2024, 4, 10),
+        (2024, 5, 9),
+        (2024, 6, 8),
+    ],
+    // 1446
+    [
+        (2024, 7, 7),
+        (2024, 8, 6),
+        (2024, 9, 5),
+        (2024, 10, 5),
+        (2024, 11, 3),
+        (2024, 12, 3),
+        (2025, 1, 1),
+        (2025, 1, 31),
+        (2025, 3, 1),
+        (2025, 3, 31),
+        (2025, 4, 29),
+        (2025, 5, 28),
+    ],
+    // 1447
+    [
+        (2025, 6, 27),
+        (2025, 7, 27),
+        (2025, 8, 25),
+        (2025, 9, 24),
+        (2025, 10, 23),
+        (2025, 11, 22),
+        (2025, 12, 21),
+        (2026, 1, 20),
+        (2026, 2, 18),
+        (2026, 3, 20),
+        (2026, 4, 18),
+        (2026, 5, 18),
+    ],
+    // 1448
+    [
+        (2026, 6, 17),
+        (2026, 7, 17),
+        (2026, 8, 15),
+        (2026, 9, 14),
+        (2026, 10, 13),
+        (2026, 11, 12),
+        (2026, 12, 11),
+        (2027, 1, 10),
+        (2027, 2, 8),
+        (2027, 3, 10),
+        (2027, 4, 8),
+        (2027, 5, 8),
+    ],
+    // 1449
+    [
+        (2027, 6, 6),
+        (2027, 7, 6),
+        (2027, 8, 4),
+        (2027, 9, 3),
+        (2027, 10, 2),
+        (2027, 11, 1),
+        (2027, 11, 30),
+        (2027, 12, 30),
+        (2028, 1, 28),
+        (2028, 2, 27),
+        (2028, 3, 27),
+        (2028, 4, 26),
+    ],
+    // 1450
+    [
+        (2028, 5, 25),
+        (2028, 6, 24),
+        (2028, 7, 23),
+        (2028, 8, 22),
+        (2028, 9, 20),
+        (2028, 10, 20),
+        (2028, 11, 18),
+        (2028, 12, 18),
+        (2029, 1, 16),
+        (2029, 2, 15),
+        (2029, 3, 16),
+        (2029, 4, 15),
+    ],
+];
+
+/// First day after the end of the table (1 Muharram 1451)
+const TABLE_END: (i32, u32, u32) = (2029, 5, 15);
+
+fn to_date((year, month, day): (i32, u32, u32)) -> NaiveDate {
+    NaiveDate::from_ymd_opt(year, month, day).expect("valid table date")
+}
+
+fn start_of_index(index: usize) -> Option<NaiveDate> {
+    match MONTH_STARTS.get(index / 12) {
+        Some(row) => Some(to_date(row[index % 12])),
+        None if index == MONTH_STARTS.len() * 12 => Some(to_date(TABLE_END)),
+        None => None,
+    }
+}
+
+fn index_of(year: i32, month: u8) -> Option<usize> {
+    if !(1..=12).contains(&month) || year < FIRST_YEAR {
+        return None;
+    }
+    let index = (year - FIRST_YEAR) as usize * 12 + month as usize - 1;
+
+    (index < MONTH_STARTS.len() * 12).then_some(index)
+}
+
+/// First day of the month or [None] if the month is not in the table
+pub(super) fn month_start(year: i32, month: u8) -> Option<NaiveDate> {
+    start_of_index(index_of(year, month)?)
+}
+
+/// Number of days of the month (29 or 30) or [None] if the month is not in the table
+pub(super) fn month_length(year: i32, month: u8) -> Option<u8> {
+    let index = index_of(year, month)?;
+    let days = start_of_index(index + 1)? - start_of_index(index)?;
+
+    Some(days.num_days() as u8)
+}
+
+/// Converts Gregorian date to Hijri `(year, month, day)` or [None] if the date is not in the table
+pub(super) fn from_gregorian(date: NaiveDate) -> Option<(i32, u8, u8)> {
+    if date < start_of_index(0)? || date >= to_date(TABLE_END) {
+        return None;
+    }
+    // the last month that starts before or at the date
+    let index = (0..MONTH_STARTS.len() * 12)
+        .rev()
+        .find(|index| start_of_index(*index).is_some_and(|start| start <= date))?;
+    let start = start_of_index(index)?;
+
+    Some((
+        FIRST_YEAR + (index / 12) as i32,
+        (index % 12) as u8 + 1,
+        (date - start).num_days() as u8 + 1,
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::hijri::algorithm;
+
+    #[test]
+    fn conversion_test() {
+        let eid_fitr = NaiveDate::from_ymd_opt(2024, 4, 10).unwrap();
+        assert_eq!(from_gregorian(eid_fitr), Some((1445, 10, 1)));
+        assert_eq!(month_start(1445, 10), Some(eid_fitr));
+
+        let ashura = NaiveDate::from_ymd_opt(2025, 7, 6).unwrap();
+        assert_eq!(from_gregorian(ashura), Some((1447, 1, 10)));
+
+        assert_eq!(
+            from_gregorian(NaiveDate::from_ymd_opt(2021, 8, 9).unwrap()),
+            None
+        );
+        assert_eq!(from_gregorian(to_date(TABLE_END)), None);
+        assert_eq!(
+            from_gregorian(NaiveDate::from_ymd_opt(2029, 5, 14).unwrap()),
+            Some((1450, 12, 30))
+        );
+        assert_eq!(month_length(1442, 1), None);
+        assert_eq!(month_length(1446, 13), None);
+        assert_eq!(month_length(1451, 1), None);
+        assert_eq!(month_length(1445, 12), Some(29));
+    }
+
+    #[test]
+    fn table_test() {
+        for year in FIRST_YEAR..FIRST_YEAR + MONTH_STARTS.len() as i32 {
+            for month in 1..=12 {
+                assert!(matches!(month_length(year, month), Some(29 | 30)));
+                // observed starts are at most one day away from the arithmetical calendar
+                let start = month_start(year, month).unwrap();
+                let tabular = algorithm::to_gregorian(year, month, 1).unwrap();
+                assert!((start - tabular).num_days().abs() <= 1);
+            }
+        }
+        // both ends of the table match the arithmetical calendar
+        assert_eq!(
+            month_start(FIRST_YEAR, 1),
+            algorithm::to_gregorian(FIRST_YEAR, 1, 1)
+        );
+        assert_eq!(
+            Some(to_date(TABLE_END)),
+            algorithm::to_gregorian(1451, 1, 1)
+        );
+    }
+}
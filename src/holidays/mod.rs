@@ -0,0 +1,339 @@
+//! Official holidays of Iran and working days.
+//!
+//! Solar holidays are fixed Jalali dates. Lunar (Hijri Qamari) holidays are computed with
+//! [hijri](crate::hijri) module, which uses month starts as observed in Iran for Hijri years
+//! 1443 to 1450 (Gregorian 2021-08-10 to 2029-05-14) and the arithmetical calendar out of that range.
+//!
+//! Lunar month starts are announced each year, so lunar holidays out of the observed range and
+//! in the future part of it may be off by a day. See "Update database" section of `Contributing.md`
+//! if you find a wrong date.
+//!
+//! # Examples
+//!
+//! ```
+//! use rust_persian_tools::holidays::{holiday_name, is_holiday, is_working_day, Options};
+//! use rust_persian_tools::jalali::JalaliDate;
+//!
+//! let nowruz = JalaliDate::new(1403, 1, 1).unwrap();
+//! assert!(is_holiday(&nowruz));
+//! assert_eq!(holiday_name(&nowruz), Some("عید نوروز"));
+//!
+//! // عید سعید فطر
+//! assert!(is_holiday(&JalaliDate::new(1403, 1, 22).unwrap()));
+//!
+//! // Friday
+//! let friday = JalaliDate::new(1403, 7, 27).unwrap();
+//! assert!(!is_holiday(&friday));
+//! assert!(!is_working_day(&friday, &Options::default()));
+//! ```
+
+use chrono::Weekday;
+
+use crate::hijri::HijriDate;
+use crate::jalali::JalaliDate;
+
+/// Fixed holidays of the Jalali calendar as (month, day, name)
+const SOLAR_HOLIDAYS: [(u8, u8, &str); 10] = [
+    (1, 1, "عید نوروز"),
+    (1, 2, "عید نوروز"),
+    (1, 3, "عید نوروز"),
+    (1, 4, "عید نوروز"),
+    (1, 12, "روز جمهوری اسلامی"),
+    (1, 13, "روز طبیعت"),
+    (3, 14, "رحلت امام خمینی"),
+    (3, 15, "قیام ۱۵ خرداد"),
+    (11, 22, "پیروزی انقلاب اسلامی"),
+    (12, 29, "روز ملی شدن صنعت نفت"),
+];
+
+/// Holidays of the Hijri Qamari calendar as (month, day, name)\
+/// Day 30 is the last day of the month, even if the month has 29 days.
+const LUNAR_HOLIDAYS: [(u8, u8, &str); 17] = [
+    (1, 9, "تاسوعای حسینی"),
+    (1, 10, "عاشورای حسینی"),
+    (2, 20, "اربعین حسینی"),
+    (2, 28, "رحلت رسول اکرم و شهادت امام حسن مجتبی"),
+    (2, 30, "شهادت امام رضا"),
+    (3, 8, "شهادت امام حسن عسکری"),
+    (3, 17, "میلاد رسول اکرم و امام جعفر صادق"),
+    (6, 3, "شهادت حضرت فاطمه زهرا"),
+    (7, 13, "ولادت امام علی"),
+    (7, 27, "مبعث رسول اکرم"),
+    (8, 15, "ولادت حضرت قائم"),
+    (9, 21, "شهادت امام علی"),
+    (10, 1, "عید سعید فطر"),
+    (10, 2, "تعطیل به مناسبت عید سعید فطر"),
+    (10, 25, "شهادت امام جعفر صادق"),
+    (12, 10, "عید سعید قربان"),
+    (12, 18, "عید سعید غدیر خم"),
+];
+
+/// An official holiday
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(PartialEq, Eq, Debug, Clone, Copy, Hash)]
+pub struct Holiday {
+    pub date: JalaliDate,
+    pub name: &'static str,
+    /// True if the holiday follows the Hijri Qamari calendar
+    pub is_lunar: bool,
+}
+
+/// How Thursdays are treated by working day functions
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(PartialEq, Eq, Debug, Clone, Copy, Hash, Default)]
+pub enum Thursday {
+    #[default]
+    FullDay,
+    HalfDay,
+    Off,
+}
+
+/// Kind of a day for working day functions
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(PartialEq, Eq, Debug, Clone, Copy, Hash)]
+pub enum DayKind {
+    Working,
+    HalfWorking,
+    Off,
+}
+
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(PartialEq, Eq, Debug, Clone, Copy, Hash, Default)]
+pub struct Options {
+    pub thursday: Thursday,
+}
+
+/// Returns all holidays of the given day, solar ones first
+///
+/// # Examples
+///
+/// ```
+/// use rust_persian_tools::holidays::holidays_of;
+/// use rust_persian_tools::jalali::JalaliDate;
+///
+/// let holidays = holidays_of(&JalaliDate::new(1403, 4, 26).unwrap());
+/// assert_eq!(holidays.len(), 1);
+/// assert_eq!(holidays[0].name, "عاشورای حسینی");
+/// assert!(holidays[0].is_lunar);
+/// ```
+pub fn holidays_of(date: &JalaliDate) -> Vec<Holiday> {
+    let solar = SOLAR_HOLIDAYS
+        .iter()
+        .filter(|(month, day, _)| *month == date.month() && *day == date.day())
+        .map(|(_, _, name)| Holiday {
+            date: *date,
+            name,
+            is_lunar: false,
+        });
+
+    let lunar_date = HijriDate::from_gregorian(date.to_gregorian()).ok();
+    let lunar = LUNAR_HOLIDAYS
+        .iter()
+        .filter(move |(month, day, _)| {
+            lunar_date.is_some_and(|lunar_date| {
+                *month == lunar_date.month()
+                    && (*day).min(lunar_date.days_in_month()) == lunar_date.day()
+            })
+        })
+        .map(|(_, _, name)| Holiday {
+            date: *date,
+            name,
+            is_lunar: true,
+        });
+
+    solar.chain(lunar).collect()
+}
+
+/// Returns all holidays of a Jalali year sorted by date
+///
+/// # Examples
+///
+/// ```
+/// use rust_persian_tools::holidays::holidays_in_year;
+///
+/// let holidays = holidays_in_year(1403);
+/// assert_eq!(holidays.len(), 27);
+/// assert_eq!(holidays[0].name, "عید نوروز");
+/// ```
+pub fn holidays_in_year(year: i32) -> Vec<Holiday> {
+    let Ok(first) = JalaliDate::new(year, 1, 1) else {
+        return Vec::new();
+    };
+
+    (0..first.days_in_year() as i64)
+        .filter_map(|offset| first.checked_add_days(offset))
+        .flat_map(|date| holidays_of(&date))
+        .collect()
+}
+
+/// Returns true if the day is an official holiday\
+/// Fridays are not holidays by themselves, see [is_working_day]
+pub fn is_holiday(date: &JalaliDate) -> bool {
+    !holidays_of(date).is_empty()
+}
+
+/// Returns name of the holiday or [None] if the day is not a holiday\
+/// If there are more than one holiday on the day, the solar one is returned.
+///
+/// # Examples
+///
+/// ```
+/// use rust_persian_tools::holidays::holiday_name;
+/// use rust_persian_tools::jalali::JalaliDate;
+///
+/// assert_eq!(
+///     holiday_name(&JalaliDate::new(1402, 11, 22).unwrap()),
+///     Some("پیروزی انقلاب اسلامی")
+/// );
+/// assert_eq!(holiday_name(&JalaliDate::new(1402, 11, 23).unwrap()), None);
+/// ```
+pub fn holiday_name(date: &JalaliDate) -> Option<&'static str> {
+    holidays_of(date).first().map(|holiday| holiday.name)
+}
+
+/// Returns kind of the day: Fridays and holidays are off, Thursdays depend on [Options::thursday]
+///
+/// # Examples
+///
+/// ```
+/// use rust_persian_tools::holidays::{day_kind, DayKind, Options, Thursday};
+/// use rust_persian_tools::jalali::JalaliDate;
+///
+/// let thursday = JalaliDate::new(1403, 7, 26).unwrap();
+/// let options = Options {
+///     thursday: Thursday::HalfDay,
+/// };
+/// assert_eq!(day_kind(&thursday, &options), DayKind::HalfWorking);
+/// assert_eq!(day_kind(&thursday, &Options::default()), DayKind::Working);
+/// ```
+pub fn day_kind(date: &JalaliDate, options: &Options) -> DayKind {
+    match date.weekday() {
+        Weekday::Fri => DayKind::Off,
+        _ if is_holiday(date) => DayKind::Off,
+        Weekday::Thu => match options.thursday {
+            Thursday::FullDay => DayKind::Working,
+            Thursday::HalfDay => DayKind::HalfWorking,
+            Thursday::Off => DayKind::Off,
+        },
+        _ => DayKind::Working,
+    }
+}
+
+/// Returns true if the day is not off, half working days are considered working days
+pub fn is_working_day(date: &JalaliDate, options: &Options) -> bool {
+    day_kind(date, options) != DayKind::Off
+}
+
+/// Adds working days to the date, negative values go backward\
+/// Returns the date itself for zero and [None] if the result is out of range.
+///
+/// # Examples
+///
+/// ```
+/// use rust_persian_tools::holidays::{add_working_days, Options, Thursday};
+/// use rust_persian_tools::jalali::JalaliDate;
+///
+/// // 1402/12/29 is a holiday, then Nowruz comes
+/// let date = JalaliDate::new(1402, 12, 28).unwrap();
+/// assert_eq!(
+///     add_working_days(date, 1, &Options::default()),
+///     Some(JalaliDate::new(1403, 1, 5).unwrap())
+/// );
+///
+/// let options = Options {
+///     thursday: Thursday::Off,
+/// };
+/// let wednesday = JalaliDate::new(1403, 7, 25).unwrap();
+/// assert_eq!(
+///     add_working_days(wednesday, 1, &options),
+///     Some(JalaliDate::new(1403, 7, 28).unwrap())
+/// );
+/// assert_eq!(add_working_days(wednesday, -3, &options), JalaliDate::new(1403, 7, 22).ok());
+/// ```
+pub fn add_working_days(date: JalaliDate, days: i64, options: &Options) -> Option<JalaliDate> {
+    let step = days.signum();
+    let mut date = date;
+    let mut remaining = days.abs();
+
+    while remaining > 0 {
+        date = date.checked_add_days(step)?;
+        if is_working_day(&date, options) {
+            remaining -= 1;
+        }
+    }
+
+    Some(date)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn date(year: i32, month: u8, day: u8) -> JalaliDate {
+        JalaliDate::new(year, month, day).unwrap()
+    }
+
+    #[test]
+    fn solar_holidays_test() {
+        for (month, day, _) in SOLAR_HOLIDAYS {
+            assert!(is_holiday(&date(1380, month, day)));
+            assert!(is_holiday(&date(1420, month, day)));
+        }
+        assert_eq!(holiday_name(&date(1403, 1, 13)), Some("روز طبیعت"));
+        assert!(!is_holiday(&date(1403, 1, 5)));
+        assert!(!is_holiday(&date(1403, 12, 30)));
+    }
+
+    #[test]
+    fn lunar_holidays_test() {
+        // Muharram 1446 starts at 1403/04/17
+        assert_eq!(holiday_name(&date(1403, 4, 25)), Some("تاسوعای حسینی"));
+        assert_eq!(holiday_name(&date(1402, 2, 2)), Some("عید سعید فطر"));
+        assert_eq!(
+            holiday_name(&date(1402, 2, 3)),
+            Some("تعطیل به مناسبت عید سعید فطر")
+        );
+        assert_eq!(holiday_name(&date(1404, 1, 11)), Some("عید سعید فطر"));
+        assert_eq!(holiday_name(&date(1404, 4, 15)), Some("عاشورای حسینی"));
+        // Safar 1446 has 30 days, Safar 1445 has 29 days
+        assert_eq!(holiday_name(&date(1403, 6, 14)), Some("شهادت امام رضا"));
+        assert_eq!(holiday_name(&date(1402, 6, 24)), Some("شهادت امام رضا"));
+        // out of the observed range, 1 Shawwal 1432 is 1390/06/09 in the arithmetical calendar
+        assert_eq!(holiday_name(&date(1390, 6, 9)), Some("عید سعید فطر"));
+        assert_eq!(crate::hijri::days_in_month(1432, 2), Some(29));
+        assert_eq!(holiday_name(&date(1389, 11, 15)), Some("شهادت امام رضا"));
+    }
+
+    #[test]
+    fn holidays_in_year_test() {
+        let holidays = holidays_in_year(1403);
+        assert!(holidays.windows(2).all(|pair| pair[0].date <= pair[1].date));
+        assert_eq!(
+            holidays.iter().filter(|holiday| !holiday.is_lunar).count(),
+            10
+        );
+        assert_eq!(
+            holidays.iter().filter(|holiday| holiday.is_lunar).count(),
+            17
+        );
+        assert!(holidays_in_year(0).is_empty());
+    }
+
+    #[test]
+    fn working_days_test() {
+        let options = Options::default();
+
+        assert_eq!(day_kind(&date(1403, 7, 27), &options), DayKind::Off);
+        assert_eq!(day_kind(&date(1403, 7, 28), &options), DayKind::Working);
+        assert_eq!(day_kind(&date(1403, 1, 1), &options), DayKind::Off);
+
+        let start = date(1403, 7, 28);
+        assert_eq!(add_working_days(start, 0, &options), Some(start));
+        assert_eq!(add_working_days(start, 6, &options), Some(date(1403, 8, 5)));
+        assert_eq!(
+            add_working_days(start, -6, &options),
+            Some(date(1403, 7, 21))
+        );
+        assert_eq!(add_working_days(date(3177, 12, 28), 5, &options), None);
+    }
+}
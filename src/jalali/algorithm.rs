@@ -0,0 +1,105 @@
+//! Conversion between Jalali and Gregorian calendars.
+//!
+//! Based on the algorithm of Kazimierz M. Borkowski (used by [jalaali-js](https://github.com/jalaali/jalaali-js)),
+//! which follows the astronomical calendar for years -61 to 3177.
+
+use chrono::{Datelike, Days, NaiveDate};
+
+pub(super) const MIN_YEAR: i32 = 1;
+pub(super) const MAX_YEAR: i32 = 3177;
+
+/// Jalali years starting a new leap cycle
+const BREAKS: [i32; 20] = [
+    -61, 9, 38, 199, 426, 686, 756, 818, 1111, 1181, 1210, 1635, 2060, 2097, 2192, 2262, 2324,
+    2394, 2456, 3178,
+];
+
+pub(super) struct JalCal {
+    /// Number of years since the last leap year (0 means `year` itself is leap)
+    pub leap: i32,
+    /// Gregorian year in which the Jalali year begins
+    pub gy: i32,
+    /// Day of March (in `gy`) which is the first day of the Jalali year (Nowruz)
+    pub march: i32,
+}
+
+/// `jy` must be in `MIN_YEAR..=MAX_YEAR + 1`, Nowruz of `MAX_YEAR + 1` is the end of `MAX_YEAR`
+pub(super) fn jal_cal(jy: i32) -> JalCal {
+    let gy = jy + 621;
+    let mut leap_j = -14;
+    let mut jp = BREAKS[0];
+    let mut jump = 0;
+
+    for jm in BREAKS.iter().skip(1) {
+        jump = jm - jp;
+        if jy < *jm {
+            break;
+        }
+        leap_j += jump / 33 * 8 + (jump % 33) / 4;
+        jp = *jm;
+    }
+
+    let mut n = jy - jp;
+    leap_j += n / 33 * 8 + (n % 33 + 3) / 4;
+    if jump % 33 == 4 && jump - n == 4 {
+        leap_j += 1;
+    }
+
+    let leap_g = gy / 4 - (gy / 100 + 1) * 3 / 4 - 150;
+    let march = 20 + leap_j - leap_g;
+
+    if jump - n < 6 {
+        n = n - jump + (jump + 4) / 33 * 33;
+    }
+    let mut leap = ((n + 1) % 33 - 1) % 4;
+    if leap == -1 {
+        leap = 4;
+    }
+
+    JalCal { leap, gy, march }
+}
+
+/// `jy` must be in `MIN_YEAR..=MAX_YEAR`, `jm` in `1..=12` and `jd` in `1..=31`
+pub(super) fn jalali_to_gregorian(jy: i32, jm: u8, jd: u8) -> Option<NaiveDate> {
+    let cal = jal_cal(jy);
+    let nowruz = NaiveDate::from_ymd_opt(cal.gy, 3, cal.march as u32)?;
+
+    let jm = jm as i64;
+    let offset = (jm - 1) * 31 - (jm / 7) * (jm - 7) + jd as i64 - 1;
+    nowruz.checked_add_days(Days::new(offset as u64))
+}
+
+/// Returns `(year, month, day)` in Jalali calendar
+pub(super) fn gregorian_to_jalali(date: NaiveDate) -> Option<(i32, u8, u8)> {
+    let gy = date.year();
+    let mut jy = gy - 621;
+    // dates before Nowruz belong to the previous year, so the last days of `MAX_YEAR`
+    // are counted back from Nowruz of the next year
+    if !(MIN_YEAR..=MAX_YEAR + 1).contains(&jy) {
+        return None;
+    }
+
+    let cal = jal_cal(jy);
+    let nowruz = NaiveDate::from_ymd_opt(gy, 3, cal.march as u32)?;
+    let mut k = (date - nowruz).num_days();
+
+    let (month, day) = if (0..=185).contains(&k) {
+        (1 + k / 31, k % 31 + 1)
+    } else {
+        if k >= 0 {
+            k -= 186;
+        } else {
+            jy -= 1;
+            k += 179;
+            if cal.leap == 1 {
+                k += 1;
+            }
+        }
+        (7 + k / 30, k % 30 + 1)
+    };
+
+    if !(MIN_YEAR..=MAX_YEAR).contains(&jy) {
+        return None;
+    }
+    Some((jy, month as u8, day as u8))
+}
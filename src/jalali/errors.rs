@@ -0,0 +1,23 @@
+use thiserror::Error;
+
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Error, Clone, Copy, Debug, Hash, PartialEq, Eq)]
+pub enum JalaliError {
+    #[error("Year {0} is out of the supported range (1..=3177)")]
+    InvalidYear(i32),
+
+    #[error("Month {0} is not valid, it must be between 1 and 12")]
+    InvalidMonth(u8),
+
+    #[error("Day {0} does not exist in the given month")]
+    InvalidDay(u8),
+
+    #[error("The date is out of the supported range")]
+    OutOfRange,
+
+    #[error("Time is not valid")]
+    InvalidTime,
+
+    #[error("Input does not match the format")]
+    InvalidFormat,
+}
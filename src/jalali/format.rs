@@ -0,0 +1,288 @@
+//! `strftime`-like formatting and parsing of Jalali dates.
+
+use chrono::{NaiveTime, Timelike, Weekday};
+
+use super::{JalaliDate, JalaliError};
+use crate::digits::{DigitsAr2En, DigitsFa2En};
+
+pub(super) const MONTH_NAMES: [&str; 12] = [
+    "فروردین",
+    "اردیبهشت",
+    "خرداد",
+    "تیر",
+    "مرداد",
+    "شهریور",
+    "مهر",
+    "آبان",
+    "آذر",
+    "دی",
+    "بهمن",
+    "اسفند",
+];
+
+pub(super) const MONTH_NAMES_EN: [&str; 12] = [
+    "Farvardin",
+    "Ordibehesht",
+    "Khordad",
+    "Tir",
+    "Mordad",
+    "Shahrivar",
+    "Mehr",
+    "Aban",
+    "Azar",
+    "Dey",
+    "Bahman",
+    "Esfand",
+];
+
+/// Starts from Saturday (first day of week in Iran)
+pub(super) const WEEKDAY_NAMES: [&str; 7] = [
+    "شنبه",
+    "یکشنبه",
+    "دوشنبه",
+    "سه\u{200c}شنبه",
+    "چهارشنبه",
+    "پنجشنبه",
+    "جمعه",
+];
+
+const AM: &str = "ق.ظ";
+const PM: &str = "ب.ظ";
+
+/// Saturday: 0 ... Friday: 6
+pub(super) fn weekday_index(weekday: Weekday) -> usize {
+    (weekday.num_days_from_monday() as usize + 2) % 7
+}
+
+pub(super) fn format(date: &JalaliDate, time: &NaiveTime, fmt: &str) -> String {
+    let mut result = String::new();
+    let mut chars = fmt.chars().peekable();
+
+    while let Some(c) = chars.next() {
+        if c != '%' {
+            result.push(c);
+            continue;
+        }
+
+        let pad = if chars.peek() == Some(&'-') {
+            chars.next();
+            false
+        } else {
+            true
+        };
+        let number = |value: u32, width: usize| {
+            if pad {
+                format!("{value:0width$}")
+            } else {
+                value.to_string()
+            }
+        };
+
+        match chars.next() {
+            Some('Y') => result += &number(date.year() as u32, 4),
+            Some('y') => result += &number(date.year() as u32 % 100, 2),
+            Some('m') => result += &number(date.month() as u32, 2),
+            Some('d') => result += &number(date.day() as u32, 2),
+            Some('j') => result += &number(date.ordinal() as u32, 3),
+            Some('H') => result += &number(time.hour(), 2),
+            Some('I') => result += &number(time.hour12().1, 2),
+            Some('M') => result += &number(time.minute(), 2),
+            Some('S') => result += &number(time.second(), 2),
+            Some('B') => result += MONTH_NAMES[date.month() as usize - 1],
+            Some('b') => result += MONTH_NAMES_EN[date.month() as usize - 1],
+            Some('A') => result += WEEKDAY_NAMES[weekday_index(date.weekday())],
+            Some('p') => result += if time.hour12().0 { PM } else { AM },
+            Some('%') => result.push('%'),
+            // unknown specifiers are kept as is
+            Some(other) => {
+                result.push('%');
+                if !pad {
+                    result.push('-');
+                }
+                result.push(other);
+            }
+            None => result.push('%'),
+        }
+    }
+
+    result
+}
+
+pub(super) fn parse(input: &str, fmt: &str) -> Result<(JalaliDate, NaiveTime), JalaliError> {
+    let input = input
+        .digits_fa_to_en()
+        .digits_ar_to_en()
+        .replace('ي', "ی")
+        .replace('ك', "ک")
+        .replace('\u{200c}', " ");
+    let mut rest = input.trim();
+
+    let (mut year, mut month, mut day) = (None, None, None);
+    let (mut hour, mut minute, mut second) = (0, 0, 0);
+    let mut is_pm = None;
+    let mut weekday = None;
+
+    let mut chars = fmt.trim().chars();
+    while let Some(c) = chars.next() {
+        if c.is_whitespace() {
+            rest = rest.trim_start();
+            continue;
+        }
+        if c != '%' {
+            rest = rest.strip_prefix(c).ok_or(JalaliError::InvalidFormat)?;
+            continue;
+        }
+
+        let mut spec = chars.next().ok_or(JalaliError::InvalidFormat)?;
+        if spec == '-' {
+            spec = chars.next().ok_or(JalaliError::InvalidFormat)?;
+        }
+
+        match spec {
+            'Y' => year = Some(take_number(&mut rest, 4)? as i32),
+            'm' => month = Some(take_number(&mut rest, 2)? as u8),
+            'd' => day = Some(take_number(&mut rest, 2)? as u8),
+            'H' | 'I' => hour = take_number(&mut rest, 2)?,
+            'M' => minute = take_number(&mut rest, 2)?,
+            'S' => second = take_number(&mut rest, 2)?,
+            'B' => month = Some(take_name(&mut rest, &MONTH_NAMES, false)? as u8 + 1),
+            'b' => month = Some(take_name(&mut rest, &MONTH_NAMES_EN, true)? as u8 + 1),
+            'A' => weekday = Some(take_weekday(&mut rest)?),
+            'p' => is_pm = Some(take_name(&mut rest, &[AM, PM], false)? == 1),
+            '%' => rest = rest.strip_prefix('%').ok_or(JalaliError::InvalidFormat)?,
+            _ => return Err(JalaliError::InvalidFormat),
+        }
+    }
+
+    if !rest.trim().is_empty() {
+        return Err(JalaliError::InvalidFormat);
+    }
+
+    if let Some(is_pm) = is_pm {
+        if !(1..=12).contains(&hour) {
+            return Err(JalaliError::InvalidTime);
+        }
+        hour = hour % 12 + if is_pm { 12 } else { 0 };
+    }
+
+    let date = match (year, month, day) {
+        (Some(year), Some(month), Some(day)) => JalaliDate::new(year, month, day)?,
+        _ => return Err(JalaliError::InvalidFormat),
+    };
+    if weekday.is_some_and(|weekday| weekday != weekday_index(date.weekday())) {
+        return Err(JalaliError::InvalidFormat);
+    }
+    let time = NaiveTime::from_hms_opt(hour, minute, second).ok_or(JalaliError::InvalidTime)?;
+
+    Ok((date, time))
+}
+
+/// Takes at least one and at most `max_len` ascii digits
+fn take_number(rest: &mut &str, max_len: usize) -> Result<u32, JalaliError> {
+    let len = rest
+        .chars()
+        .take(max_len)
+        .take_while(|c| c.is_ascii_digit())
+        .count();
+    if len == 0 {
+        return Err(JalaliError::InvalidFormat);
+    }
+    let number = rest[..len]
+        .parse()
+        .map_err(|_| JalaliError::InvalidFormat)?;
+    *rest = &rest[len..];
+    Ok(number)
+}
+
+/// Returns index of the longest name that input starts with
+fn take_name(rest: &mut &str, names: &[&str], ignore_case: bool) -> Result<usize, JalaliError> {
+    let (index, len) = names
+        .iter()
+        .enumerate()
+        .filter(|(_, name)| {
+            rest.get(..name.len()).is_some_and(|prefix| {
+                if ignore_case {
+                    prefix.eq_ignore_ascii_case(name)
+                } else {
+                    prefix == **name
+                }
+            })
+        })
+        .map(|(index, name)| (index, name.len()))
+        .max_by_key(|(_, len)| *len)
+        .ok_or(JalaliError::InvalidFormat)?;
+    *rest = &rest[len..];
+    Ok(index)
+}
+
+fn take_weekday(rest: &mut &str) -> Result<usize, JalaliError> {
+    // half-spaces of the input are already replaced by space
+    let names = WEEKDAY_NAMES.map(|name| name.replace('\u{200c}', " "));
+    let names = names.iter().map(String::as_str).collect::<Vec<_>>();
+    take_name(rest, &names, false)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn format_test() {
+        let date = JalaliDate::new(1403, 7, 6).unwrap();
+        let time = NaiveTime::from_hms_opt(14, 5, 9).unwrap();
+
+        assert_eq!(format(&date, &time, "%Y/%m/%d"), "1403/07/06");
+        assert_eq!(format(&date, &time, "%-d %B %Y"), "6 مهر 1403");
+        assert_eq!(format(&date, &time, "%A %-d %b"), "جمعه 6 Mehr");
+        assert_eq!(format(&date, &time, "%H:%M:%S"), "14:05:09");
+        assert_eq!(format(&date, &time, "%I:%M %p"), "02:05 ب.ظ");
+        assert_eq!(format(&date, &time, "%j %y %% %Q"), "192 03 % %Q");
+    }
+
+    #[test]
+    fn parse_test() {
+        let date = JalaliDate::new(1403, 7, 26).unwrap();
+
+        assert_eq!(parse("1403/07/26", "%Y/%m/%d"), Ok((date, NaiveTime::MIN)));
+        assert_eq!(
+            parse("۱۴۰۳/۰۷/۲۶ ۱۴:۳۰", "%Y/%m/%d %H:%M"),
+            Ok((date, NaiveTime::from_hms_opt(14, 30, 0).unwrap()))
+        );
+        assert_eq!(
+            parse("پنجشنبه ۲۶ مهر ۱۴۰۳", "%A %d %B %Y"),
+            Ok((date, NaiveTime::MIN))
+        );
+        assert_eq!(
+            parse("26 mehr 1403 02:30 ب.ظ", "%d %b %Y %I:%M %p"),
+            Ok((date, NaiveTime::from_hms_opt(14, 30, 0).unwrap()))
+        );
+        assert_eq!(
+            parse("1 اردیبهشت 1403", "%d %B %Y"),
+            Ok((JalaliDate::new(1403, 2, 1).unwrap(), NaiveTime::MIN))
+        );
+    }
+
+    #[test]
+    fn parse_fail_test() {
+        assert_eq!(
+            parse("1403/07/26", "%Y-%m-%d"),
+            Err(JalaliError::InvalidFormat)
+        );
+        assert_eq!(
+            parse("1403/07/26 extra", "%Y/%m/%d"),
+            Err(JalaliError::InvalidFormat)
+        );
+        assert_eq!(
+            parse("جمعه ۲۶ مهر ۱۴۰۳", "%A %d %B %Y"),
+            Err(JalaliError::InvalidFormat)
+        );
+        assert_eq!(
+            parse("1404/12/30", "%Y/%m/%d"),
+            Err(JalaliError::InvalidDay(30))
+        );
+        assert_eq!(
+            parse("1403/07/26 25:00", "%Y/%m/%d %H:%M"),
+            Err(JalaliError::InvalidTime)
+        );
+    }
+}
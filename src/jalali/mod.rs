@@ -0,0 +1,742 @@
+//! Jalali (Solar Hijri / Shamsi) calendar (`jalali` Cargo feature).
+//!
+//! Conversion between Jalali and Gregorian calendars is lossless in both directions
+//! and interoperates with [chrono] types.
+//!
+//! #### Example
+//! ```rust
+//! use rust_persian_tools::jalali::{JalaliDate, JalaliDateTime};
+//! use chrono::{NaiveDate, NaiveTime, Weekday};
+//!
+//! let date = JalaliDate::new(1403, 1, 1).unwrap();
+//! assert_eq!(date.to_gregorian(), NaiveDate::from_ymd_opt(2024, 3, 20).unwrap());
+//! assert_eq!(date.weekday(), Weekday::Wed);
+//! assert!(date.is_leap_year());
+//! assert_eq!(date.to_string(), "1403/01/01");
+//!
+//! let gregorian = NaiveDate::from_ymd_opt(2024, 10, 17).unwrap();
+//! let date = JalaliDate::try_from(gregorian).unwrap();
+//! assert_eq!((date.year(), date.month(), date.day()), (1403, 7, 26));
+//! assert_eq!(NaiveDate::from(date), gregorian);
+//!
+//! let time = NaiveTime::from_hms_opt(14, 30, 0).unwrap();
+//! let datetime = JalaliDateTime::new(date, time);
+//! assert_eq!(datetime.to_string(), "1403/07/26 14:30:00");
+//! ```
+//!
+//! #### Formatting and parsing
+//! Both [JalaliDate] and [JalaliDateTime] have `strftime`-like `format` and `parse_from_str` methods.\
+//! Parsing accepts Persian, Arabic and English digits.
+//!
+//! | Specifier | Example | Description |
+//! |-----------|---------|-------------|
+//! | `%Y` | `1403` | Year, zero-padded to 4 digits |
+//! | `%y` | `03` | Last two digits of year (formatting only) |
+//! | `%m` | `07` | Month number, zero-padded to 2 digits |
+//! | `%d` | `06` | Day of month, zero-padded to 2 digits |
+//! | `%j` | `192` | Day of year, zero-padded to 3 digits (formatting only) |
+//! | `%B` | `مهر` | Persian month name |
+//! | `%b` | `Mehr` | English month name |
+//! | `%A` | `شنبه` | Persian weekday name |
+//! | `%H` | `14` | Hour (00-23) |
+//! | `%I` | `02` | Hour (01-12) |
+//! | `%M` | `30` | Minute |
+//! | `%S` | `00` | Second |
+//! | `%p` | `ب.ظ` | `ق.ظ` (AM) or `ب.ظ` (PM) |
+//! | `%%` | `%` | A literal `%` |
+//!
+//! Adding `-` after `%` disables zero-padding of numbers, e.g. `%-d`.
+//!
+//! ```rust
+//! use rust_persian_tools::jalali::{JalaliDate, JalaliDateTime};
+//! use rust_persian_tools::digits::Language;
+//!
+//! let date = JalaliDate::new(1403, 7, 26).unwrap();
+//! assert_eq!(date.format("%A %-d %B %Y"), "پنجشنبه 26 مهر 1403");
+//! assert_eq!(
+//!     date.format_with_digits("%A %-d %B %Y", Language::Persian),
+//!     "پنجشنبه ۲۶ مهر ۱۴۰۳"
+//! );
+//!
+//! let datetime: JalaliDateTime = "۱۴۰۳/۰۷/۲۶ ۱۴:۳۰".parse().unwrap();
+//! assert_eq!(datetime.format("%Y/%m/%d %H:%M"), "1403/07/26 14:30");
+//! assert_eq!(
+//!     JalaliDate::parse_from_str("۲۶ مهر ۱۴۰۳", "%d %B %Y").unwrap(),
+//!     date
+//! );
+//! ```
+
+mod algorithm;
+pub mod errors;
+mod format;
+
+use std::fmt;
+use std::str::FromStr;
+
+use chrono::{Datelike, NaiveDate, NaiveDateTime, NaiveTime, Weekday};
+
+use self::algorithm::{gregorian_to_jalali, jal_cal, jalali_to_gregorian, MAX_YEAR, MIN_YEAR};
+pub use self::errors::JalaliError;
+use self::format::{weekday_index, MONTH_NAMES, WEEKDAY_NAMES};
+use crate::digits::{en_to_language, Language};
+
+/// Rule used to decide whether a Jalali year is leap or not.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(PartialEq, Eq, Debug, Clone, Copy, Hash, Default)]
+pub enum LeapYearRule {
+    /// Follows the official (astronomical) calendar of Iran for years 1 to 3177.\
+    /// This is the rule used for all conversions in this module.\
+    /// Years outside of that range fall back to [LeapYearRule::Arithmetic33].
+    #[default]
+    Astronomical,
+    /// Simple 33-year cycle: a year is leap if its remainder by 33 is one of 1, 5, 9, 13, 17, 22, 26 or 30.\
+    /// Matches [LeapYearRule::Astronomical] for years 1178 to 1633.
+    Arithmetic33,
+}
+
+impl LeapYearRule {
+    /// Returns true if the given Jalali year is leap based on this rule.
+    /// ```
+    /// use rust_persian_tools::jalali::LeapYearRule;
+    ///
+    /// assert!(LeapYearRule::Astronomical.is_leap_year(1403));
+    /// assert!(LeapYearRule::Arithmetic33.is_leap_year(1403));
+    /// assert!(!LeapYearRule::Arithmetic33.is_leap_year(1404));
+    /// ```
+    pub fn is_leap_year(&self, year: i32) -> bool {
+        match self {
+            LeapYearRule::Astronomical if (MIN_YEAR..=MAX_YEAR).contains(&year) => {
+                jal_cal(year).leap == 0
+            }
+            _ => matches!(year.rem_euclid(33), 1 | 5 | 9 | 13 | 17 | 22 | 26 | 30),
+        }
+    }
+}
+
+/// Returns true if the given Jalali year is leap (has 366 days).\
+/// Uses [LeapYearRule::Astronomical].
+/// ```
+/// use rust_persian_tools::jalali::is_leap_year;
+///
+/// assert!(is_leap_year(1399));
+/// assert!(!is_leap_year(1400));
+/// ```
+pub fn is_leap_year(year: i32) -> bool {
+    LeapYearRule::Astronomical.is_leap_year(year)
+}
+
+/// Returns number of days in the given month of a Jalali year or [None] if month is not in `1..=12`.
+/// ```
+/// use rust_persian_tools::jalali::days_in_month;
+///
+/// assert_eq!(days_in_month(1403, 1), Some(31));
+/// assert_eq!(days_in_month(1403, 7), Some(30));
+/// assert_eq!(days_in_month(1403, 12), Some(30));
+/// assert_eq!(days_in_month(1404, 12), Some(29));
+/// assert_eq!(days_in_month(1404, 13), None);
+/// ```
+pub fn days_in_month(year: i32, month: u8) -> Option<u8> {
+    match month {
+        1..=6 => Some(31),
+        7..=11 => Some(30),
+        12 if is_leap_year(year) => Some(30),
+        12 => Some(29),
+        _ => None,
+    }
+}
+
+/// A date in Jalali calendar.\
+/// The struct is always valid; use [JalaliDate::new] or conversion from [NaiveDate] to build one.\
+/// With `serde` Cargo feature it is deserialized through [JalaliDate::new], so invalid dates are rejected.
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(try_from = "JalaliDateFields")
+)]
+#[derive(PartialEq, Eq, PartialOrd, Ord, Debug, Clone, Copy, Hash)]
+pub struct JalaliDate {
+    year: i32,
+    month: u8,
+    day: u8,
+}
+
+/// Fields of [JalaliDate] before validation
+#[cfg(feature = "serde")]
+#[derive(serde::Deserialize)]
+struct JalaliDateFields {
+    year: i32,
+    month: u8,
+    day: u8,
+}
+
+#[cfg(feature = "serde")]
+impl TryFrom<JalaliDateFields> for JalaliDate {
+    type Error = JalaliError;
+
+    fn try_from(fields: JalaliDateFields) -> Result<Self, Self::Error> {
+        JalaliDate::new(fields.year, fields.month, fields.day)
+    }
+}
+
+impl JalaliDate {
+    /// Builds a Jalali date and checks its validity.
+    /// ```
+    /// use rust_persian_tools::jalali::{JalaliDate, JalaliError};
+    ///
+    /// assert!(JalaliDate::new(1403, 12, 30).is_ok());
+    /// assert_eq!(JalaliDate::new(1404, 12, 30), Err(JalaliError::InvalidDay(30)));
+    /// assert_eq!(JalaliDate::new(1404, 13, 1), Err(JalaliError::InvalidMonth(13)));
+    /// ```
+    pub fn new(year: i32, month: u8, day: u8) -> Result<Self, JalaliError> {
+        if !(MIN_YEAR..=MAX_YEAR).contains(&year) {
+            return Err(JalaliError::InvalidYear(year));
+        }
+        let max_day = days_in_month(year, month).ok_or(JalaliError::InvalidMonth(month))?;
+        if day == 0 || day > max_day {
+            return Err(JalaliError::InvalidDay(day));
+        }
+        Ok(JalaliDate { year, month, day })
+    }
+
+    /// Converts a Gregorian date to Jalali.
+    /// ```
+    /// use rust_persian_tools::jalali::JalaliDate;
+    /// use chrono::NaiveDate;
+    ///
+    /// let date = JalaliDate::from_gregorian(NaiveDate::from_ymd_opt(1979, 2, 11).unwrap()).unwrap();
+    /// assert_eq!(date, JalaliDate::new(1357, 11, 22).unwrap());
+    /// ```
+    pub fn from_gregorian(date: NaiveDate) -> Result<Self, JalaliError> {
+        let (year, month, day) = gregorian_to_jalali(date).ok_or(JalaliError::OutOfRange)?;
+        Ok(JalaliDate { year, month, day })
+    }
+
+    /// Converts the date to Gregorian.
+    /// ```
+    /// use rust_persian_tools::jalali::JalaliDate;
+    /// use chrono::NaiveDate;
+    ///
+    /// let date = JalaliDate::new(1399, 12, 30).unwrap();
+    /// assert_eq!(date.to_gregorian(), NaiveDate::from_ymd_opt(2021, 3, 20).unwrap());
+    /// ```
+    pub fn to_gregorian(&self) -> NaiveDate {
+        // this is safe because the date is validated on creation
+        jalali_to_gregorian(self.year, self.month, self.day).expect("valid jalali date")
+    }
+
+    pub fn year(&self) -> i32 {
+        self.year
+    }
+
+    /// Month number starting from 1 (فروردین)
+    pub fn month(&self) -> u8 {
+        self.month
+    }
+
+    /// Day of month starting from 1
+    pub fn day(&self) -> u8 {
+        self.day
+    }
+
+    /// Day of year starting from 1 (1 فروردین)
+    pub fn ordinal(&self) -> u16 {
+        let month = self.month as u16;
+        if month <= 6 {
+            (month - 1) * 31 + self.day as u16
+        } else {
+            186 + (month - 7) * 30 + self.day as u16
+        }
+    }
+
+    /// Day of week.
+    pub fn weekday(&self) -> Weekday {
+        self.to_gregorian().weekday()
+    }
+
+    pub fn is_leap_year(&self) -> bool {
+        is_leap_year(self.year)
+    }
+
+    pub fn days_in_month(&self) -> u8 {
+        // this is safe because the month is validated on creation
+        days_in_month(self.year, self.month).unwrap_or_default()
+    }
+
+    pub fn days_in_year(&self) -> u16 {
+        if self.is_leap_year() {
+            366
+        } else {
+            365
+        }
+    }
+
+    /// Adds (or subtracts if negative) the given number of days.\
+    /// Returns [None] if the result is out of the supported range.
+    /// ```
+    /// use rust_persian_tools::jalali::JalaliDate;
+    ///
+    /// let date = JalaliDate::new(1402, 12, 29).unwrap();
+    /// assert_eq!(date.checked_add_days(1), Some(JalaliDate::new(1403, 1, 1).unwrap()));
+    /// assert_eq!(date.checked_add_days(-29), Some(JalaliDate::new(1402, 11, 30).unwrap()));
+    /// ```
+    pub fn checked_add_days(self, days: i64) -> Option<Self> {
+        let date = self
+            .to_gregorian()
+            .checked_add_signed(chrono::Duration::try_days(days)?)?;
+        JalaliDate::from_gregorian(date).ok()
+    }
+
+    /// Adds (or subtracts if negative) the given number of months.\
+    /// Day is clamped to the last day of the resulting month.\
+    /// Returns [None] if the result is out of the supported range.
+    /// ```
+    /// use rust_persian_tools::jalali::JalaliDate;
+    ///
+    /// let date = JalaliDate::new(1403, 6, 31).unwrap();
+    /// assert_eq!(date.checked_add_months(1), Some(JalaliDate::new(1403, 7, 30).unwrap()));
+    /// assert_eq!(date.checked_add_months(-7), Some(JalaliDate::new(1402, 11, 30).unwrap()));
+    /// assert_eq!(date.checked_add_months(6), Some(JalaliDate::new(1403, 12, 30).unwrap()));
+    /// ```
+    pub fn checked_add_months(self, months: i32) -> Option<Self> {
+        let index = (self.year * 12 + self.month as i32 - 1).checked_add(months)?;
+        let year = index.div_euclid(12);
+        let month = (index.rem_euclid(12) + 1) as u8;
+        let day = self.day.min(days_in_month(year, month)?);
+        JalaliDate::new(year, month, day).ok()
+    }
+
+    /// Persian name of the month, e.g. "فروردین"
+    pub fn month_name(&self) -> &'static str {
+        MONTH_NAMES[self.month as usize - 1]
+    }
+
+    /// Persian name of the weekday, e.g. "شنبه"
+    pub fn weekday_name(&self) -> &'static str {
+        WEEKDAY_NAMES[weekday_index(self.weekday())]
+    }
+
+    /// Formats the date with English digits.\
+    /// See [module](crate::jalali) documentation for list of specifiers.
+    /// ```
+    /// use rust_persian_tools::jalali::JalaliDate;
+    ///
+    /// let date = JalaliDate::new(1403, 1, 1).unwrap();
+    /// assert_eq!(date.format("%Y-%m-%d"), "1403-01-01");
+    /// assert_eq!(date.format("%A %-d %B"), "چهارشنبه 1 فروردین");
+    /// ```
+    pub fn format(&self, fmt: impl AsRef<str>) -> String {
+        format::format(self, &NaiveTime::MIN, fmt.as_ref())
+    }
+
+    /// Formats the date with digits of the given [Language].
+    /// ```
+    /// use rust_persian_tools::jalali::JalaliDate;
+    /// use rust_persian_tools::digits::Language;
+    ///
+    /// let date = JalaliDate::new(1403, 1, 1).unwrap();
+    /// assert_eq!(date.format_with_digits("%Y/%m/%d", Language::Persian), "۱۴۰۳/۰۱/۰۱");
+    /// assert_eq!(date.format_with_digits("%Y/%m/%d", Language::Arabic), "١٤٠٣/٠١/٠١");
+    /// ```
+    pub fn format_with_digits(&self, fmt: impl AsRef<str>, digits: Language) -> String {
+        en_to_language(self.format(fmt), digits)
+    }
+
+    /// Parses a date from the given format.\
+    /// Time specifiers are accepted but ignored.\
+    /// See [module](crate::jalali) documentation for list of specifiers.
+    /// ```
+    /// use rust_persian_tools::jalali::{JalaliDate, JalaliError};
+    ///
+    /// let date = JalaliDate::parse_from_str("۱۴۰۳-۰۱-۰۱", "%Y-%m-%d").unwrap();
+    /// assert_eq!(date, JalaliDate::new(1403, 1, 1).unwrap());
+    /// assert_eq!(
+    ///     JalaliDate::parse_from_str("1403/01/01", "%Y-%m-%d"),
+    ///     Err(JalaliError::InvalidFormat)
+    /// );
+    /// ```
+    pub fn parse_from_str(
+        input: impl AsRef<str>,
+        fmt: impl AsRef<str>,
+    ) -> Result<Self, JalaliError> {
+        format::parse(input.as_ref(), fmt.as_ref()).map(|(date, _)| date)
+    }
+}
+
+impl FromStr for JalaliDate {
+    type Err = JalaliError;
+
+    /// Parses `YYYY/MM/DD` or `YYYY-MM-DD` with any kind of digits
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        parse_any(s, &["%Y/%m/%d", "%Y-%m-%d"]).map(|(date, _)| date)
+    }
+}
+
+impl TryFrom<NaiveDate> for JalaliDate {
+    type Error = JalaliError;
+
+    fn try_from(date: NaiveDate) -> Result<Self, Self::Error> {
+        JalaliDate::from_gregorian(date)
+    }
+}
+
+impl From<JalaliDate> for NaiveDate {
+    fn from(date: JalaliDate) -> Self {
+        date.to_gregorian()
+    }
+}
+
+impl fmt::Display for JalaliDate {
+    /// Formats date as `YYYY/MM/DD`
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:04}/{:02}/{:02}", self.year, self.month, self.day)
+    }
+}
+
+/// A date and time in Jalali calendar.\
+/// Time part is a [NaiveTime] so all of [chrono::Timelike] methods are available on it.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(PartialEq, Eq, PartialOrd, Ord, Debug, Clone, Copy, Hash)]
+pub struct JalaliDateTime {
+    date: JalaliDate,
+    time: NaiveTime,
+}
+
+impl JalaliDateTime {
+    pub fn new(date: JalaliDate, time: NaiveTime) -> Self {
+        JalaliDateTime { date, time }
+    }
+
+    /// Converts a Gregorian date time to Jalali.
+    /// ```
+    /// use rust_persian_tools::jalali::JalaliDateTime;
+    /// use chrono::NaiveDate;
+    ///
+    /// let gregorian = NaiveDate::from_ymd_opt(2024, 3, 19)
+    ///     .unwrap()
+    ///     .and_hms_opt(23, 59, 59)
+    ///     .unwrap();
+    /// let datetime = JalaliDateTime::from_gregorian(gregorian).unwrap();
+    /// assert_eq!(datetime.to_string(), "1402/12/29 23:59:59");
+    /// assert_eq!(datetime.to_gregorian(), gregorian);
+    /// ```
+    pub fn from_gregorian(datetime: NaiveDateTime) -> Result<Self, JalaliError> {
+        Ok(JalaliDateTime {
+            date: JalaliDate::from_gregorian(datetime.date())?,
+            time: datetime.time(),
+        })
+    }
+
+    pub fn to_gregorian(&self) -> NaiveDateTime {
+        self.date.to_gregorian().and_time(self.time)
+    }
+
+    pub fn date(&self) -> JalaliDate {
+        self.date
+    }
+
+    pub fn time(&self) -> NaiveTime {
+        self.time
+    }
+
+    /// Formats the date time with English digits.\
+    /// See [module](crate::jalali) documentation for list of specifiers.
+    /// ```
+    /// use rust_persian_tools::jalali::JalaliDateTime;
+    ///
+    /// let datetime: JalaliDateTime = "1403/01/01 08:05:00".parse().unwrap();
+    /// assert_eq!(datetime.format("%Y/%m/%d %I:%M %p"), "1403/01/01 08:05 ق.ظ");
+    /// ```
+    pub fn format(&self, fmt: impl AsRef<str>) -> String {
+        format::format(&self.date, &self.time, fmt.as_ref())
+    }
+
+    /// Formats the date time with digits of the given [Language].
+    /// ```
+    /// use rust_persian_tools::jalali::JalaliDateTime;
+    /// use rust_persian_tools::digits::Language;
+    ///
+    /// let datetime: JalaliDateTime = "1403/01/01 08:05:00".parse().unwrap();
+    /// assert_eq!(
+    ///     datetime.format_with_digits("%Y/%m/%d %H:%M", Language::Persian),
+    ///     "۱۴۰۳/۰۱/۰۱ ۰۸:۰۵"
+    /// );
+    /// ```
+    pub fn format_with_digits(&self, fmt: impl AsRef<str>, digits: Language) -> String {
+        en_to_language(self.format(fmt), digits)
+    }
+
+    /// Parses a date time from the given format.\
+    /// Missing time specifiers are considered zero.\
+    /// See [module](crate::jalali) documentation for list of specifiers.
+    /// ```
+    /// use rust_persian_tools::jalali::JalaliDateTime;
+    ///
+    /// let datetime =
+    ///     JalaliDateTime::parse_from_str("۲۶ مهر ۱۴۰۳ ساعت ۱۴:۳۰", "%d %B %Y ساعت %H:%M").unwrap();
+    /// assert_eq!(datetime.to_string(), "1403/07/26 14:30:00");
+    /// ```
+    pub fn parse_from_str(
+        input: impl AsRef<str>,
+        fmt: impl AsRef<str>,
+    ) -> Result<Self, JalaliError> {
+        format::parse(input.as_ref(), fmt.as_ref())
+            .map(|(date, time)| JalaliDateTime { date, time })
+    }
+}
+
+impl FromStr for JalaliDateTime {
+    type Err = JalaliError;
+
+    /// Parses `YYYY/MM/DD HH:MM:SS` or `YYYY/MM/DD HH:MM` or `YYYY/MM/DD` with any kind of digits\
+    /// `-` is also accepted as date separator.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        parse_any(
+            s,
+            &[
+                "%Y/%m/%d %H:%M:%S",
+                "%Y/%m/%d %H:%M",
+                "%Y/%m/%d",
+                "%Y-%m-%d %H:%M:%S",
+                "%Y-%m-%d %H:%M",
+                "%Y-%m-%d",
+            ],
+        )
+        .map(|(date, time)| JalaliDateTime { date, time })
+    }
+}
+
+impl TryFrom<NaiveDateTime> for JalaliDateTime {
+    type Error = JalaliError;
+
+    fn try_from(datetime: NaiveDateTime) -> Result<Self, Self::Error> {
+        JalaliDateTime::from_gregorian(datetime)
+    }
+}
+
+impl From<JalaliDateTime> for NaiveDateTime {
+    fn from(datetime: JalaliDateTime) -> Self {
+        datetime.to_gregorian()
+    }
+}
+
+impl From<JalaliDate> for JalaliDateTime {
+    /// Start of the day (00:00:00)
+    fn from(date: JalaliDate) -> Self {
+        JalaliDateTime::new(date, NaiveTime::MIN)
+    }
+}
+
+impl fmt::Display for JalaliDateTime {
+    /// Formats date time as `YYYY/MM/DD HH:MM:SS`
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} {}", self.date, self.time.format("%H:%M:%S"))
+    }
+}
+
+/// Tries each format in order and returns the first success.\
+/// If none of them match, the most specific error is returned.
+fn parse_any(input: &str, formats: &[&str]) -> Result<(JalaliDate, NaiveTime), JalaliError> {
+    let mut error = JalaliError::InvalidFormat;
+    for fmt in formats {
+        match format::parse(input, fmt) {
+            Ok(parsed) => return Ok(parsed),
+            Err(JalaliError::InvalidFormat) => {}
+            Err(e) => error = e,
+        }
+    }
+    Err(error)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn gregorian(year: i32, month: u32, day: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(year, month, day).unwrap()
+    }
+
+    #[test]
+    fn jalali_to_gregorian_test() {
+        let cases = [
+            ((1403, 1, 1), gregorian(2024, 3, 20)),
+            ((1402, 1, 1), gregorian(2023, 3, 21)),
+            ((1404, 1, 1), gregorian(2025, 3, 21)),
+            ((1403, 12, 30), gregorian(2025, 3, 20)),
+            ((1399, 12, 30), gregorian(2021, 3, 20)),
+            ((1357, 11, 22), gregorian(1979, 2, 11)),
+            ((1403, 7, 26), gregorian(2024, 10, 17)),
+            ((1, 1, 1), gregorian(622, 3, 22)),
+        ];
+        for ((year, month, day), expected) in cases {
+            let date = JalaliDate::new(year, month, day).unwrap();
+            assert_eq!(date.to_gregorian(), expected);
+            assert_eq!(JalaliDate::from_gregorian(expected), Ok(date));
+        }
+    }
+
+    #[test]
+    fn round_trip_test() {
+        let mut date = gregorian(1900, 1, 1);
+        let end = gregorian(2100, 1, 1);
+        let mut previous = JalaliDate::from_gregorian(date).unwrap();
+        while date < end {
+            date = date.succ_opt().unwrap();
+            let jalali = JalaliDate::from_gregorian(date).unwrap();
+            assert_eq!(jalali.to_gregorian(), date);
+            assert!(jalali > previous);
+            assert_eq!(
+                JalaliDate::new(jalali.year, jalali.month, jalali.day),
+                Ok(jalali)
+            );
+            previous = jalali;
+        }
+    }
+
+    #[test]
+    fn supported_range_test() {
+        let first = JalaliDate::new(1, 1, 1).unwrap();
+        let last = JalaliDate::new(3177, 12, days_in_month(3177, 12).unwrap()).unwrap();
+        for date in [first, last] {
+            assert_eq!(JalaliDate::from_gregorian(date.to_gregorian()), Ok(date));
+            assert_eq!(date.checked_add_days(0), Some(date));
+        }
+        assert_eq!(last.to_gregorian(), gregorian(3799, 3, 19));
+        assert_eq!(
+            first.checked_add_days(1),
+            Some(JalaliDate::new(1, 1, 2).unwrap())
+        );
+        assert_eq!(
+            last.checked_add_days(-1),
+            Some(JalaliDate::new(3177, 12, 28).unwrap())
+        );
+        assert_eq!(first.checked_add_days(-1), None);
+        assert_eq!(last.checked_add_days(1), None);
+
+        // every day of the last year
+        let mut date = JalaliDate::new(3177, 1, 1).unwrap().to_gregorian();
+        while date <= last.to_gregorian() {
+            let jalali = JalaliDate::from_gregorian(date).unwrap();
+            assert_eq!(jalali.to_gregorian(), date);
+            date = date.succ_opt().unwrap();
+        }
+    }
+
+    #[test]
+    fn leap_year_test() {
+        for year in [1375, 1379, 1383, 1387, 1391, 1395, 1399, 1403, 1408] {
+            assert!(is_leap_year(year), "{year}");
+        }
+        for year in [1400, 1401, 1402, 1404, 1405, 1406, 1407] {
+            assert!(!is_leap_year(year), "{year}");
+        }
+        for year in 1178..1634 {
+            assert_eq!(
+                LeapYearRule::Astronomical.is_leap_year(year),
+                LeapYearRule::Arithmetic33.is_leap_year(year),
+                "{year}"
+            );
+        }
+    }
+
+    #[test]
+    fn calendar_info_test() {
+        let date = JalaliDate::new(1403, 7, 26).unwrap();
+        assert_eq!(date.weekday(), Weekday::Thu);
+        assert_eq!(date.ordinal(), 6 * 31 + 26);
+        assert_eq!(date.days_in_month(), 30);
+        assert_eq!(date.days_in_year(), 366);
+        assert_eq!(JalaliDate::new(1403, 1, 1).unwrap().ordinal(), 1);
+        assert_eq!(JalaliDate::new(1403, 12, 30).unwrap().ordinal(), 366);
+    }
+
+    #[test]
+    fn invalid_date_test() {
+        assert_eq!(JalaliDate::new(0, 1, 1), Err(JalaliError::InvalidYear(0)));
+        assert_eq!(
+            JalaliDate::new(1403, 0, 1),
+            Err(JalaliError::InvalidMonth(0))
+        );
+        assert_eq!(
+            JalaliDate::new(1403, 7, 31),
+            Err(JalaliError::InvalidDay(31))
+        );
+        assert_eq!(JalaliDate::new(1403, 1, 0), Err(JalaliError::InvalidDay(0)));
+        assert_eq!(
+            JalaliDate::from_gregorian(gregorian(600, 1, 1)),
+            Err(JalaliError::OutOfRange)
+        );
+    }
+
+    #[test]
+    fn from_str_test() {
+        let date = JalaliDate::new(1403, 7, 26).unwrap();
+        assert_eq!("1403/07/26".parse(), Ok(date));
+        assert_eq!("۱۴۰۳-۷-۲۶".parse(), Ok(date));
+        assert_eq!(
+            "1404/12/30".parse::<JalaliDate>(),
+            Err(JalaliError::InvalidDay(30))
+        );
+        assert_eq!(
+            "1403.07.26".parse::<JalaliDate>(),
+            Err(JalaliError::InvalidFormat)
+        );
+
+        let time = NaiveTime::from_hms_opt(14, 30, 0).unwrap();
+        let datetime = JalaliDateTime::new(date, time);
+        assert_eq!("۱۴۰۳/۰۷/۲۶ ۱۴:۳۰".parse(), Ok(datetime));
+        assert_eq!("١٤٠٣/٠٧/٢٦ ١٤:٣٠:٠٠".parse(), Ok(datetime));
+        assert_eq!("1403-07-26 14:30".parse(), Ok(datetime));
+        assert_eq!("1403/07/26".parse(), Ok(JalaliDateTime::from(date)));
+        assert_eq!(datetime.to_string().parse(), Ok(datetime));
+    }
+
+    #[test]
+    fn names_test() {
+        let date = JalaliDate::new(1403, 12, 1).unwrap();
+        assert_eq!(date.month_name(), "اسفند");
+        assert_eq!(date.weekday(), Weekday::Wed);
+        assert_eq!(date.weekday_name(), "چهارشنبه");
+        let date = JalaliDate::new(1403, 12, 7).unwrap();
+        assert_eq!(date.weekday(), Weekday::Tue);
+        assert_eq!(date.weekday_name(), "سه\u{200c}شنبه");
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn serde_test() {
+        let date = JalaliDate::new(1403, 12, 30).unwrap();
+        let json_str = serde_json::to_string(&date).unwrap();
+        assert_eq!(json_str, "{\"year\":1403,\"month\":12,\"day\":30}");
+        assert_eq!(serde_json::from_str::<JalaliDate>(&json_str).unwrap(), date);
+
+        for invalid in [
+            "{\"year\":1403,\"month\":13,\"day\":40}",
+            "{\"year\":1403,\"month\":0,\"day\":1}",
+            "{\"year\":1404,\"month\":12,\"day\":30}",
+            "{\"year\":0,\"month\":1,\"day\":1}",
+        ] {
+            assert!(
+                serde_json::from_str::<JalaliDate>(invalid).is_err(),
+                "{invalid}"
+            );
+        }
+        assert_eq!(
+            serde_json::from_str::<JalaliDate>("{\"year\":1403,\"month\":13,\"day\":1}")
+                .err()
+                .unwrap()
+                .to_string(),
+            JalaliError::InvalidMonth(13).to_string()
+        );
+
+        let datetime = JalaliDateTime::new(date, NaiveTime::from_hms_opt(8, 0, 0).unwrap());
+        let json_str = serde_json::to_string(&datetime).unwrap();
+        assert_eq!(
+            serde_json::from_str::<JalaliDateTime>(&json_str).unwrap(),
+            datetime
+        );
+        assert!(serde_json::from_str::<JalaliDateTime>(
+            "{\"date\":{\"year\":1403,\"month\":0,\"day\":1},\"time\":\"08:00:00\"}"
+        )
+        .is_err());
+    }
+}
@@ -11,6 +11,7 @@
     feature = "verity-card-number",
     feature = "phone-number",
     feature = "bill",
+    feature = "bill-barcode",
     feature = "number-to-words",
     feature = "get-bank-name-by-card-number",
     feature = "extract-card-number",
@@ -21,6 +22,9 @@
     feature = "sheba",
     feature = "time-diff",
     feature = "number-plate",
+    feature = "jalali",
+    feature = "holidays",
+    feature = "hijri",
 )))]
 compile_error!("No available Cargo feature is included");
 
@@ -63,6 +67,9 @@ pub mod phone_number;
 #[cfg(feature = "bill")]
 pub mod bill;
 
+#[cfg(feature = "bill-barcode")]
+pub mod bill_barcode;
+
 #[cfg(feature = "number-to-words")]
 pub mod number_to_words;
 
@@ -89,3 +96,12 @@ pub mod words_to_number;
 
 #[cfg(feature = "sheba")]
 pub mod sheba;
+
+#[cfg(feature = "jalali")]
+pub mod jalali;
+
+#[cfg(feature = "holidays")]
+pub mod holidays;
+
+#[cfg(feature = "hijri")]
+pub mod hijri;
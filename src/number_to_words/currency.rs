@@ -0,0 +1,198 @@
+use crate::bill::CurrencyType;
+
+use super::error::NumberToWordsError;
+use super::{number_to_words_u128, Options};
+
+/// Options of [amount_to_words]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(PartialEq, Eq, Debug, Clone, Copy, Hash)]
+pub struct CurrencyOptions {
+    /// Currency that the amount is written in
+    pub currency: CurrencyType,
+    /// Prefix the words with "فقط", as written on cheques
+    pub only: bool,
+    /// Suffix the words with "تمام", as written on cheques
+    pub complete: bool,
+}
+
+impl Default for CurrencyOptions {
+    fn default() -> Self {
+        CurrencyOptions {
+            currency: CurrencyType::Rials,
+            only: false,
+            complete: false,
+        }
+    }
+}
+
+fn currency_name(currency: CurrencyType) -> &'static str {
+    match currency {
+        CurrencyType::Rials => "ریال",
+        CurrencyType::Tomans => "تومان",
+    }
+}
+
+/// Convert an amount from one currency to another.\
+/// Returns [None] if the result overflows or some Rials are left when converting to Tomans.
+/// ```
+/// # use rust_persian_tools::number_to_words::convert_amount;
+/// # use rust_persian_tools::bill::CurrencyType;
+/// assert_eq!(convert_amount(1200, CurrencyType::Tomans, CurrencyType::Rials), Some(12000));
+/// assert_eq!(convert_amount(12000, CurrencyType::Rials, CurrencyType::Tomans), Some(1200));
+/// assert_eq!(convert_amount(12005, CurrencyType::Rials, CurrencyType::Tomans), None);
+/// ```
+pub fn convert_amount(amount: u64, from: CurrencyType, to: CurrencyType) -> Option<u64> {
+    match (from, to) {
+        (CurrencyType::Rials, CurrencyType::Tomans) => match (amount / 10, amount % 10) {
+            (tomans, 0) => Some(tomans),
+            _ => None,
+        },
+        (CurrencyType::Tomans, CurrencyType::Rials) => amount.checked_mul(10),
+        _ => Some(amount),
+    }
+}
+
+/// Convert an amount of money to persian words with the currency name.\
+/// `from` is the currency of `amount` and the words are written in [CurrencyOptions::currency].\
+/// Rials that are left when writing in Tomans are written separately, e.g. "دو تومان و پنج ریال".
+/// ```
+/// # use rust_persian_tools::number_to_words::{amount_to_words, CurrencyOptions};
+/// # use rust_persian_tools::bill::CurrencyType;
+/// assert_eq!(
+///     amount_to_words(1_200_000, CurrencyType::Rials, &CurrencyOptions::default()),
+///     Ok("یک میلیون و دویست هزار ریال".to_string())
+/// );
+///
+/// let options = CurrencyOptions {
+///     currency: CurrencyType::Tomans,
+///     only: true,
+///     complete: true,
+/// };
+/// assert_eq!(
+///     amount_to_words(1_200_000, CurrencyType::Rials, &options),
+///     Ok("فقط صد و بیست هزار تومان تمام".to_string())
+/// );
+/// assert_eq!(
+///     amount_to_words(25, CurrencyType::Rials, &options),
+///     Ok("فقط دو تومان و پنج ریال تمام".to_string())
+/// );
+/// ```
+pub fn amount_to_words(
+    amount: u64,
+    from: CurrencyType,
+    options: &CurrencyOptions,
+) -> Result<String, NumberToWordsError> {
+    let rials = match from {
+        CurrencyType::Rials => amount as u128,
+        CurrencyType::Tomans => amount as u128 * 10,
+    };
+
+    let (main, rials_left) = match options.currency {
+        CurrencyType::Rials => (rials, 0),
+        CurrencyType::Tomans => (rials / 10, rials % 10),
+    };
+
+    let mut words = format!(
+        "{} {}",
+        number_to_words_u128(main, &Options::default())?,
+        currency_name(options.currency)
+    );
+    if rials_left != 0 {
+        // "پنج ریال" instead of "صفر تومان و پنج ریال"
+        let rials_words = format!(
+            "{} {}",
+            number_to_words_u128(rials_left, &Options::default())?,
+            currency_name(CurrencyType::Rials)
+        );
+        words = if main == 0 {
+            rials_words
+        } else {
+            format!("{words} و {rials_words}")
+        };
+    }
+
+    if options.only {
+        words = format!("فقط {words}");
+    }
+    if options.complete {
+        words = format!("{words} تمام");
+    }
+
+    Ok(words)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn convert_amount_test() {
+        assert_eq!(
+            convert_amount(0, CurrencyType::Rials, CurrencyType::Tomans),
+            Some(0)
+        );
+        assert_eq!(
+            convert_amount(7, CurrencyType::Rials, CurrencyType::Rials),
+            Some(7)
+        );
+        assert_eq!(
+            convert_amount(u64::MAX, CurrencyType::Tomans, CurrencyType::Rials),
+            None
+        );
+    }
+
+    #[test]
+    fn amount_to_words_test() {
+        let rials = CurrencyOptions::default();
+        let tomans = CurrencyOptions {
+            currency: CurrencyType::Tomans,
+            ..rials
+        };
+
+        assert_eq!(
+            amount_to_words(0, CurrencyType::Rials, &rials),
+            Ok("صفر ریال".to_string())
+        );
+        assert_eq!(
+            amount_to_words(120_000, CurrencyType::Tomans, &tomans),
+            Ok("صد و بیست هزار تومان".to_string())
+        );
+        assert_eq!(
+            amount_to_words(120_000, CurrencyType::Tomans, &rials),
+            Ok("یک میلیون و دویست هزار ریال".to_string())
+        );
+        assert_eq!(
+            amount_to_words(5, CurrencyType::Rials, &tomans),
+            Ok("پنج ریال".to_string())
+        );
+        assert_eq!(
+            amount_to_words(12_345, CurrencyType::Rials, &tomans),
+            Ok("یک هزار و دویست و سی و چهار تومان و پنج ریال".to_string())
+        );
+        // u64::MAX Tomans does not fit in u64 Rials
+        let words = amount_to_words(u64::MAX, CurrencyType::Tomans, &rials).unwrap();
+        assert!(words.starts_with("صد و هشتاد و چهار کوینتیلیون و "));
+        assert!(words.ends_with(" و صد و پنجاه ریال"));
+    }
+
+    #[test]
+    fn formal_test() {
+        let options = CurrencyOptions {
+            only: true,
+            ..Default::default()
+        };
+        assert_eq!(
+            amount_to_words(1_000, CurrencyType::Rials, &options),
+            Ok("فقط یک هزار ریال".to_string())
+        );
+
+        let options = CurrencyOptions {
+            complete: true,
+            ..Default::default()
+        };
+        assert_eq!(
+            amount_to_words(1_000, CurrencyType::Rials, &options),
+            Ok("یک هزار ریال تمام".to_string())
+        );
+    }
+}
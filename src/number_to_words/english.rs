@@ -0,0 +1,63 @@
+const ONES: [&str; 20] = [
+    "zero",
+    "one",
+    "two",
+    "three",
+    "four",
+    "five",
+    "six",
+    "seven",
+    "eight",
+    "nine",
+    "ten",
+    "eleven",
+    "twelve",
+    "thirteen",
+    "fourteen",
+    "fifteen",
+    "sixteen",
+    "seventeen",
+    "eighteen",
+    "nineteen",
+];
+
+const TENS: [&str; 10] = [
+    "", "", "twenty", "thirty", "forty", "fifty", "sixty", "seventy", "eighty", "ninety",
+];
+
+/// Converts number to english words for (1..1000), e.g. "three hundred twenty-one"
+pub(super) fn three_digit_number_to_english(num: u64) -> String {
+    let num = num as usize % 1000;
+    let mut parts = Vec::new();
+
+    if num >= 100 {
+        parts.push(format!("{} hundred", ONES[num / 100]));
+    }
+    match num % 100 {
+        0 => {}
+        rest @ 1..=19 => parts.push(ONES[rest].to_string()),
+        rest if rest % 10 == 0 => parts.push(TENS[rest / 10].to_string()),
+        rest => parts.push(format!("{}-{}", TENS[rest / 10], ONES[rest % 10])),
+    }
+
+    parts.join(" ")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn three_digit_number_to_english_test() {
+        assert_eq!(three_digit_number_to_english(7), "seven");
+        assert_eq!(three_digit_number_to_english(15), "fifteen");
+        assert_eq!(three_digit_number_to_english(40), "forty");
+        assert_eq!(three_digit_number_to_english(99), "ninety-nine");
+        assert_eq!(three_digit_number_to_english(100), "one hundred");
+        assert_eq!(three_digit_number_to_english(311), "three hundred eleven");
+        assert_eq!(
+            three_digit_number_to_english(921),
+            "nine hundred twenty-one"
+        );
+    }
+}
@@ -12,6 +12,9 @@ pub enum NumberToWordsError {
     #[error("Overflow occurred while processing the integer: \"{0}\"")]
     Overflow(String),
 
+    #[error("Denominator of the fraction can not be zero.")]
+    ZeroDenominator,
+
     #[error("Unknown error occurred: \"{0}\"")]
     Unknown(String),
 
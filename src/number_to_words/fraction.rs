@@ -0,0 +1,199 @@
+use crate::add_ordinal_suffix::add_ordinal_suffix;
+
+use super::error::NumberToWordsError;
+use super::number_to_words;
+
+/// Maximum number of fractional digits that can be named (صد کوآدریلیونم)
+const MAX_FRACTION_DIGITS: usize = 17;
+
+/// Convert a decimal number to persian words.\
+/// Trailing zeros of the fractional part are ignored.
+/// ```
+/// # use rust_persian_tools::number_to_words::decimal_to_words;
+/// assert_eq!(decimal_to_words(12.5), Ok("دوازده و پنج دهم".to_string()));
+/// assert_eq!(decimal_to_words(-0.25), Ok("منفی بیست و پنج صدم".to_string()));
+/// assert_eq!(decimal_to_words(3.0), Ok("سه".to_string()));
+/// assert!(decimal_to_words(f64::NAN).is_err());
+/// ```
+pub fn decimal_to_words(input: f64) -> Result<String, NumberToWordsError> {
+    if !input.is_finite() {
+        return Err(NumberToWordsError::InvalidInteger(input.to_string()));
+    }
+
+    // `Display` of f64 is the shortest representation and never uses exponent
+    let input = input.to_string();
+    decimal_str_to_words(&input, &input)
+}
+
+/// Convert a common fraction to persian words.
+/// ```
+/// # use rust_persian_tools::number_to_words::{fraction_to_words, NumberToWordsError};
+/// assert_eq!(fraction_to_words(3, 4), Ok("سه چهارم".to_string()));
+/// assert_eq!(fraction_to_words(1, 2), Ok("یک دوم".to_string()));
+/// assert_eq!(fraction_to_words(-2, 3), Ok("منفی دو سوم".to_string()));
+/// assert_eq!(fraction_to_words(7, 1000), Ok("هفت هزارم".to_string()));
+/// assert_eq!(fraction_to_words(1, 0), Err(NumberToWordsError::ZeroDenominator));
+/// ```
+pub fn fraction_to_words(numerator: i64, denominator: i64) -> Result<String, NumberToWordsError> {
+    if denominator == 0 {
+        return Err(NumberToWordsError::ZeroDenominator);
+    }
+    if numerator == 0 {
+        return number_to_words(0);
+    }
+
+    let is_negative = (numerator < 0) != (denominator < 0);
+    let numerator = number_to_words(
+        numerator
+            .unsigned_abs()
+            .try_into()
+            .map_err(|_| NumberToWordsError::Overflow(numerator.to_string()))?,
+    )?;
+
+    let result = match denominator.unsigned_abs() {
+        1 => numerator,
+        denominator => format!("{} {}", numerator, ordinal_denominator(denominator)?),
+    };
+
+    if is_negative {
+        Ok(format!("منفی {result}"))
+    } else {
+        Ok(result)
+    }
+}
+
+/// Convert a percentage to persian words.
+/// ```
+/// # use rust_persian_tools::number_to_words::percent_to_words;
+/// assert_eq!(percent_to_words(25.0), Ok("بیست و پنج درصد".to_string()));
+/// assert_eq!(percent_to_words(2.5), Ok("دو و پنج دهم درصد".to_string()));
+/// ```
+pub fn percent_to_words(input: f64) -> Result<String, NumberToWordsError> {
+    Ok(format!("{} درصد", decimal_to_words(input)?))
+}
+
+/// Converts "12.5" or "-.5" to words, `original` is used in errors
+pub(super) fn decimal_str_to_words(
+    input: &str,
+    original: &str,
+) -> Result<String, NumberToWordsError> {
+    let invalid = || NumberToWordsError::InvalidInteger(original.to_string());
+
+    let (is_negative, unsigned) = match input.strip_prefix('-') {
+        Some(rest) => (true, rest),
+        None => (false, input.strip_prefix('+').unwrap_or(input)),
+    };
+    let (integer, fraction) = unsigned.split_once('.').unwrap_or((unsigned, ""));
+    if integer.is_empty() && fraction.is_empty() {
+        return Err(invalid());
+    }
+    if !integer
+        .bytes()
+        .chain(fraction.bytes())
+        .all(|b| b.is_ascii_digit())
+    {
+        return Err(invalid());
+    }
+
+    let integer = match integer {
+        "" => 0,
+        integer => integer
+            .parse::<i64>()
+            .map_err(|_| NumberToWordsError::Overflow(original.to_string()))?,
+    };
+    let fraction = fraction.trim_end_matches('0');
+    if fraction.len() > MAX_FRACTION_DIGITS {
+        return Err(NumberToWordsError::Overflow(original.to_string()));
+    }
+
+    let mut parts = Vec::new();
+    if integer != 0 || fraction.is_empty() {
+        parts.push(number_to_words(integer)?);
+    }
+    if !fraction.is_empty() {
+        let numerator = fraction.parse::<i64>().map_err(|_| invalid())?;
+        let denominator = 10_u64.pow(fraction.len() as u32);
+        parts.push(format!(
+            "{} {}",
+            number_to_words(numerator)?,
+            ordinal_denominator(denominator)?
+        ));
+    }
+
+    let result = parts.join(" و ");
+    if is_negative && result != "صفر" {
+        Ok(format!("منفی {result}"))
+    } else {
+        Ok(result)
+    }
+}
+
+/// 4 -> چهارم, 1000 -> هزارم
+fn ordinal_denominator(denominator: u64) -> Result<String, NumberToWordsError> {
+    let words = number_to_words(
+        denominator
+            .try_into()
+            .map_err(|_| NumberToWordsError::Overflow(denominator.to_string()))?,
+    )?;
+    // "یک هزارم" is said "هزارم"
+    let words = words.strip_prefix("یک ").unwrap_or(&words);
+
+    Ok(add_ordinal_suffix(words))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn decimal_test() {
+        assert_eq!(decimal_to_words(0.0), Ok("صفر".to_string()));
+        assert_eq!(decimal_to_words(0.5), Ok("پنج دهم".to_string()));
+        assert_eq!(decimal_to_words(1.001), Ok("یک و یک هزارم".to_string()));
+        assert_eq!(
+            decimal_to_words(100.0001),
+            Ok("صد و یک ده هزارم".to_string())
+        );
+        assert_eq!(decimal_to_words(0.000003), Ok("سه میلیونم".to_string()));
+        assert_eq!(
+            decimal_to_words(f64::INFINITY),
+            Err(NumberToWordsError::InvalidInteger("inf".to_string()))
+        );
+    }
+
+    #[test]
+    fn decimal_str_test() {
+        assert_eq!(
+            decimal_str_to_words("-.5", "-.5"),
+            Ok("منفی پنج دهم".to_string())
+        );
+        assert_eq!(decimal_str_to_words("-0.0", "-0.0"), Ok("صفر".to_string()));
+        assert_eq!(decimal_str_to_words("7.", "7."), Ok("هفت".to_string()));
+        assert_eq!(
+            decimal_str_to_words("7.2.1", "۷٫۲٫۱"),
+            Err(NumberToWordsError::InvalidInteger("۷٫۲٫۱".to_string()))
+        );
+        assert_eq!(
+            decimal_str_to_words(".", "."),
+            Err(NumberToWordsError::InvalidInteger(".".to_string()))
+        );
+        let long = "0.0000000000000000001";
+        assert_eq!(
+            decimal_str_to_words(long, long),
+            Err(NumberToWordsError::Overflow(long.to_string()))
+        );
+    }
+
+    #[test]
+    fn fraction_test() {
+        assert_eq!(fraction_to_words(0, 5), Ok("صفر".to_string()));
+        assert_eq!(fraction_to_words(5, 1), Ok("پنج".to_string()));
+        assert_eq!(fraction_to_words(5, -1), Ok("منفی پنج".to_string()));
+        assert_eq!(fraction_to_words(-1, -3), Ok("یک سوم".to_string()));
+        assert_eq!(fraction_to_words(2, 21), Ok("دو بیست و یکم".to_string()));
+        assert_eq!(fraction_to_words(1, 30), Ok("یک سی اُم".to_string()));
+        assert_eq!(fraction_to_words(1, 100), Ok("یک صدم".to_string()));
+        assert!(fraction_to_words(i64::MIN, 2).is_err());
+        assert!(fraction_to_words(1, i64::MIN).is_err());
+    }
+}
@@ -32,15 +32,7 @@ pub(super) fn get_defined_word(num: u64) -> Result<&'static str, NumberToWordsEr
         100 => "صد",
         200 => "دویست",
         300 => "سیصد",
-        400 => "چهار صد",
         500 => "پانصد",
-        600 => "شش صد",
-        700 => "هفت صد",
-        800 => "هشت صد",
-        900 => "نه صد",
         _ => return Err(NumberToWordsError::Internal),
     })
 }
-
-pub(crate) static WITH_3N_ZEROS: [&str; 6] =
-    ["", "هزار", "میلیون", "میلیارد", "تریلیون", "کوآدریلیون"];
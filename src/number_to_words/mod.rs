@@ -1,15 +1,79 @@
+#[cfg(feature = "bill")]
+mod currency;
+mod english;
 pub mod error;
+mod fraction;
 mod get_word;
+mod ordinal;
+mod scale;
 mod three_digits;
 
-use crate::number_to_words::get_word::WITH_3N_ZEROS;
+use crate::number_to_words::english::three_digit_number_to_english;
 use crate::number_to_words::three_digits::three_digit_number_to_words;
-use std::cmp::Ordering;
 use std::num::IntErrorKind;
 
 use crate::commas::remove_commas::remove_commas;
+pub use crate::digits::Language;
+use crate::digits::{DigitsAr2En, DigitsFa2En};
 
+#[cfg(feature = "bill")]
+pub use self::currency::{amount_to_words, convert_amount, CurrencyOptions};
 pub use self::error::NumberToWordsError;
+pub use self::fraction::{decimal_to_words, fraction_to_words, percent_to_words};
+pub use self::ordinal::{number_to_ordinal_words, FirstWord, OrdinalForm, OrdinalOptions};
+pub use self::scale::Scale;
+
+/// Spacing of the hundreds that are written in two parts, such as 400
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(PartialEq, Eq, Debug, Clone, Copy, Hash, Default)]
+pub enum Hundreds {
+    /// "چهار صد"
+    #[default]
+    Spaced,
+    /// "چهارصد"
+    Joined,
+    /// "چهار‌صد" with a zero-width non-joiner (half-space)
+    HalfSpace,
+}
+
+impl Hundreds {
+    fn separator(&self) -> &'static str {
+        match self {
+            Hundreds::Spaced => " ",
+            Hundreds::Joined => "",
+            Hundreds::HalfSpace => "\u{200c}",
+        }
+    }
+}
+
+/// Style of the words, the default is Persian with "چهار صد", "صد" and "یک هزار"\
+/// All styles of [Scale::Iranian] and [Scale::Short] are accepted by [words_to_number](crate::words_to_number::words_to_number),
+/// [Scale::Long] is accepted by [words_to_number_with_scale](crate::words_to_number::words_to_number_with_scale)
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(PartialEq, Eq, Debug, Clone, Copy, Hash)]
+pub struct Options {
+    pub scale: Scale,
+    /// Language of the words, Arabic is spelled the same as Persian\
+    /// English is written as "twenty-one thousand three hundred", the other fields only affect Persian
+    pub language: Language,
+    pub hundreds: Hundreds,
+    /// "یک صد" instead of "صد", spaced as [Options::hundreds]
+    pub explicit_one_hundred: bool,
+    /// "هزار" instead of "یک هزار"
+    pub omit_one_thousand: bool,
+}
+
+impl Default for Options {
+    fn default() -> Self {
+        Options {
+            scale: Scale::default(),
+            language: Language::Persian,
+            hundreds: Hundreds::default(),
+            explicit_one_hundred: false,
+            omit_one_thousand: false,
+        }
+    }
+}
 
 /// Convert integer number to persian words.\
 /// If you want to pass string as input see: [number_to_words_str]
@@ -18,52 +82,133 @@ pub use self::error::NumberToWordsError;
 /// assert_eq!(number_to_words(33), Ok("سی و سه".to_string()));
 /// ```
 pub fn number_to_words(input: i64) -> Result<String, NumberToWordsError> {
-    let mut result = String::new();
-
-    let mut num: u64 = match input.cmp(&0) {
-        Ordering::Less => {
-            result.push_str("منفی ");
-            let num = input.abs().try_into();
-            match num {
-                Ok(v) => v,
-                Err(_e) => return Err(NumberToWordsError::Unknown(input.to_string())),
-            }
-        }
-        Ordering::Equal => return Ok("صفر".to_string()),
-        Ordering::Greater => {
-            let num = input.abs().try_into();
-            match num {
-                Ok(v) => v,
-                Err(_e) => return Err(NumberToWordsError::Unknown(input.to_string())),
-            }
-        }
+    let words = unsigned_to_words(&input.unsigned_abs().to_string(), &Options::default())?;
+
+    if input < 0 {
+        Ok(format!("منفی {words}"))
+    } else {
+        Ok(words)
+    }
+}
+
+/// Convert u128 number to persian or english words with the given [Options].\
+/// For numbers larger than u128 see: [digits_to_words]
+/// ```
+/// # use rust_persian_tools::number_to_words::{number_to_words_u128, Language, Options, Scale};
+/// assert_eq!(
+///     number_to_words_u128(2_000_000_000_000_000_000, &Options::default()),
+///     Ok("دو کوینتیلیون".to_string())
+/// );
+/// assert_eq!(
+///     number_to_words_u128(3_000_000_000, &Options { scale: Scale::Short, ..Default::default() }),
+///     Ok("سه بیلیون".to_string())
+/// );
+/// assert_eq!(
+///     number_to_words_u128(3_000_000_000_000, &Options { scale: Scale::Long, ..Default::default() }),
+///     Ok("سه بیلیون".to_string())
+/// );
+/// assert_eq!(
+///     number_to_words_u128(21_300, &Options { language: Language::English, ..Default::default() }),
+///     Ok("twenty-one thousand three hundred".to_string())
+/// );
+/// ```
+pub fn number_to_words_u128(input: u128, options: &Options) -> Result<String, NumberToWordsError> {
+    unsigned_to_words(&input.to_string(), options)
+}
+
+/// Convert an integer of any length in string to persian words with the given [Options].\
+/// Commas and Persian or Arabic digits are accepted.\
+/// Names beyond the largest name of the scale are composed, e.g. "هزار دسیلیون".
+/// ```
+/// # use rust_persian_tools::number_to_words::{digits_to_words, Options};
+/// assert_eq!(
+///     digits_to_words("-۱۲,۰۰۰,۰۰۰,۰۰۰,۰۰۰,۰۰۰,۰۰۰,۰۰۰,۰۰۰", &Options::default()),
+///     Ok("منفی دوازده سپتیلیون".to_string())
+/// );
+/// assert_eq!(
+///     digits_to_words(format!("1{}", "0".repeat(36)), &Options::default()),
+///     Ok("یک هزار دسیلیون".to_string())
+/// );
+/// ```
+pub fn digits_to_words(
+    digits: impl AsRef<str>,
+    options: &Options,
+) -> Result<String, NumberToWordsError> {
+    let input = digits.as_ref();
+    let digits = remove_commas(input).digits_fa_to_en().digits_ar_to_en();
+    let digits = digits.trim();
+
+    let (is_negative, digits) = match digits.strip_prefix('-') {
+        Some(rest) => (true, rest),
+        None => (false, digits.strip_prefix('+').unwrap_or(digits)),
     };
+    if digits.is_empty() {
+        return Err(NumberToWordsError::EmptyString);
+    }
+    if !digits.bytes().all(|b| b.is_ascii_digit()) {
+        return Err(NumberToWordsError::InvalidInteger(input.to_string()));
+    }
+
+    let words = unsigned_to_words(digits, options)?;
+    if is_negative && digits.bytes().any(|b| b != b'0') {
+        match options.language {
+            Language::English => Ok(format!("minus {words}")),
+            Language::Persian | Language::Arabic => Ok(format!("منفی {words}")),
+        }
+    } else {
+        Ok(words)
+    }
+}
 
-    let mut chunks = Vec::<u64>::new();
-    while num != 0 {
-        let next = num % 1000;
-        num /= 1000;
-        chunks.push(next);
+/// `digits` must only contain ascii digits
+fn unsigned_to_words(digits: &str, options: &Options) -> Result<String, NumberToWordsError> {
+    let english = options.language == Language::English;
+    let digits = digits.trim_start_matches('0');
+    if digits.is_empty() {
+        return Ok(if english { "zero" } else { "صفر" }.to_string());
     }
-    chunks.reverse();
-    let mut l = chunks.len();
-    for c in chunks {
-        if c == 0 {
+
+    // split into chunks of three digits from the right
+    let first_len = match digits.len() % 3 {
+        0 => 3,
+        len => len,
+    };
+    let mut chunks = vec![&digits[..first_len]];
+    chunks.extend(
+        (first_len..digits.len())
+            .step_by(3)
+            .map(|start| &digits[start..start + 3]),
+    );
+
+    let mut parts = Vec::new();
+    for (index, chunk) in chunks.iter().enumerate() {
+        let chunk = chunk
+            .parse::<u64>()
+            .map_err(|_| NumberToWordsError::Internal)?;
+        if chunk == 0 {
             continue;
         }
-        l -= 1;
-        result.push_str(three_digit_number_to_words(c)?.as_str());
-        result.push(' ');
-        result.push_str(WITH_3N_ZEROS[l]);
-        result.push_str(" و ");
+
+        let group = chunks.len() - index - 1;
+        let words = if english {
+            three_digit_number_to_english(chunk)
+        } else {
+            three_digit_number_to_words(chunk, options)?
+        };
+        let name = options.scale.name(group, options.language);
+        match (group, chunk) {
+            (0, _) => parts.push(words),
+            (1, 1) if options.omit_one_thousand && !english => parts.push(name),
+            _ => parts.push(format!("{} {}", words, name)),
+        }
     }
 
-    // remove " و " at the end
-    result.remove(result.len() - 3);
-    Ok(result.trim().to_owned())
+    Ok(parts.join(if english { " " } else { " و " }))
 }
 
-/// Convert integer number to persian words.
+/// Convert a number in string to persian words.\
+/// Besides integers, decimals (with `.` or Persian `٫` separator), common fractions and percentages are supported.\
+/// Persian and Arabic digits are accepted.
 /// ### Example:
 /// ```
 /// # use rust_persian_tools::number_to_words::number_to_words_str;
@@ -71,22 +216,58 @@ pub fn number_to_words(input: i64) -> Result<String, NumberToWordsError> {
 ///     number_to_words_str("33"),
 ///     Ok("سی و سه".to_string())
 /// );
+/// assert_eq!(
+///     number_to_words_str("۱۲٫۵"),
+///     Ok("دوازده و پنج دهم".to_string())
+/// );
+/// assert_eq!(
+///     number_to_words_str("3/4"),
+///     Ok("سه چهارم".to_string())
+/// );
+/// assert_eq!(
+///     number_to_words_str("۲۵٪"),
+///     Ok("بیست و پنج درصد".to_string())
+/// );
 /// ```
 /// If you want to pass integer use: [number_to_words]
 pub fn number_to_words_str(num: impl AsRef<str>) -> Result<String, NumberToWordsError> {
     let num = num.as_ref();
+    let normalized = remove_commas(num)
+        .digits_fa_to_en()
+        .digits_ar_to_en()
+        .replace('٬', "")
+        .replace('٫', ".");
+    let normalized = normalized.trim();
 
-    match remove_commas(num).parse::<i64>() {
-        Ok(value) => Ok(number_to_words(value)?),
-        Err(e) => match e.kind() {
-            IntErrorKind::Empty => Err(NumberToWordsError::EmptyString),
-            IntErrorKind::InvalidDigit => Err(NumberToWordsError::InvalidInteger(num.to_string())),
-            IntErrorKind::PosOverflow | IntErrorKind::NegOverflow => {
-                Err(NumberToWordsError::Overflow(num.to_string()))
-            }
-            _ => Err(NumberToWordsError::Unknown(num.to_string())),
-        },
+    if let Some(percent) = normalized
+        .strip_suffix('%')
+        .or_else(|| normalized.strip_suffix('٪'))
+    {
+        return Ok(format!("{} درصد", number_to_words_str(percent)?));
+    }
+    if let Some((numerator, denominator)) = normalized.split_once('/') {
+        return fraction_to_words(
+            parse_integer(numerator.trim(), num)?,
+            parse_integer(denominator.trim(), num)?,
+        );
     }
+    if normalized.contains('.') {
+        return fraction::decimal_str_to_words(normalized, num);
+    }
+
+    number_to_words(parse_integer(normalized, num)?)
+}
+
+/// `original` is used in errors
+fn parse_integer(input: &str, original: &str) -> Result<i64, NumberToWordsError> {
+    input.parse::<i64>().map_err(|e| match e.kind() {
+        IntErrorKind::Empty => NumberToWordsError::EmptyString,
+        IntErrorKind::InvalidDigit => NumberToWordsError::InvalidInteger(original.to_string()),
+        IntErrorKind::PosOverflow | IntErrorKind::NegOverflow => {
+            NumberToWordsError::Overflow(original.to_string())
+        }
+        _ => NumberToWordsError::Unknown(original.to_string()),
+    })
 }
 
 #[cfg(test)]
@@ -105,6 +286,14 @@ mod tests {
             Ok("سه هزار و هشت صد و بیست و پنج".to_string())
         );
         assert_eq!(number_to_words(-4), Ok("منفی چهار".to_string()));
+        assert_eq!(number_to_words(1000001), Ok("یک میلیون و یک".to_string()));
+        // 11 to 19 are single words
+        assert_eq!(number_to_words(211), Ok("دویست و یازده".to_string()));
+        assert_eq!(number_to_words(1015), Ok("یک هزار و پانزده".to_string()));
+        assert_eq!(
+            number_to_words(i64::MIN),
+            Ok("منفی نه کوینتیلیون و دویست و بیست و سه کوآدریلیون و سیصد و هفتاد و دو تریلیون و سی و شش میلیارد و هشت صد و پنجاه و چهار میلیون و هفت صد و هفتاد و پنج هزار و هشت صد و هشت".to_string())
+        );
         assert_eq!(number_to_words(30000000000), Ok("سی میلیارد".to_string()));
         assert_eq!(
             number_to_words(987654321),
@@ -120,6 +309,215 @@ mod tests {
         );
     }
 
+    #[test]
+    fn big_number_test() {
+        let options = Options::default();
+        assert_eq!(number_to_words_u128(0, &options), Ok("صفر".to_string()));
+        assert_eq!(
+            number_to_words_u128(u128::MAX, &options),
+            Ok("سیصد و چهل هزار دسیلیون و دویست و هشتاد و دو دسیلیون و سیصد و شصت و شش نونیلیون و نه صد و بیست اکتیلیون و نه صد و سی و هشت سپتیلیون و چهار صد و شصت و سه سکستیلیون و چهار صد و شصت و سه کوینتیلیون و سیصد و هفتاد و چهار کوآدریلیون و شش صد و هفت تریلیون و چهار صد و سی و یک میلیارد و هفت صد و شصت و هشت میلیون و دویست و یازده هزار و چهار صد و پنجاه و پنج".to_string())
+        );
+        assert_eq!(
+            digits_to_words(
+                "1000000000000",
+                &Options {
+                    scale: Scale::Long,
+                    ..Default::default()
+                }
+            ),
+            Ok("یک بیلیون".to_string())
+        );
+        assert_eq!(
+            digits_to_words(
+                "1000000000000000",
+                &Options {
+                    scale: Scale::Long,
+                    ..Default::default()
+                }
+            ),
+            Ok("یک بیلیارد".to_string())
+        );
+        assert_eq!(
+            digits_to_words("0001002", &options),
+            Ok("یک هزار و دو".to_string())
+        );
+        assert_eq!(digits_to_words("-0", &options), Ok("صفر".to_string()));
+        assert_eq!(
+            digits_to_words(format!("2{}", "0".repeat(39)), &options),
+            Ok("دو میلیون دسیلیون".to_string())
+        );
+        assert_eq!(
+            digits_to_words("", &options),
+            Err(NumberToWordsError::EmptyString)
+        );
+        assert_eq!(
+            digits_to_words("12a", &options),
+            Err(NumberToWordsError::InvalidInteger("12a".to_string()))
+        );
+    }
+
+    #[test]
+    fn style_test() {
+        let options = Options {
+            hundreds: Hundreds::Joined,
+            explicit_one_hundred: true,
+            omit_one_thousand: true,
+            ..Default::default()
+        };
+        assert_eq!(
+            number_to_words_u128(1_100, &options),
+            Ok("هزار و یکصد".to_string())
+        );
+        assert_eq!(
+            number_to_words_u128(2_001_400, &options),
+            Ok("دو میلیون و هزار و چهارصد".to_string())
+        );
+        assert_eq!(
+            number_to_words_u128(101_000, &options),
+            Ok("یکصد و یک هزار".to_string())
+        );
+        assert_eq!(
+            digits_to_words(
+                "-900",
+                &Options {
+                    hundreds: Hundreds::HalfSpace,
+                    ..Default::default()
+                }
+            ),
+            Ok("منفی نه\u{200c}صد".to_string())
+        );
+    }
+
+    #[test]
+    fn english_test() {
+        let options = Options {
+            language: Language::English,
+            ..Default::default()
+        };
+        assert_eq!(number_to_words_u128(0, &options), Ok("zero".to_string()));
+        assert_eq!(
+            number_to_words_u128(1_000_001, &options),
+            Ok("one million one".to_string())
+        );
+        assert_eq!(
+            number_to_words_u128(2_040_000_117, &options),
+            Ok("two billion forty million one hundred seventeen".to_string())
+        );
+        assert_eq!(
+            digits_to_words("-۱۲,۰۰۰", &options),
+            Ok("minus twelve thousand".to_string())
+        );
+        assert_eq!(digits_to_words("-0", &options), Ok("zero".to_string()));
+        assert_eq!(
+            digits_to_words(
+                "3000000000",
+                &Options {
+                    scale: Scale::Long,
+                    ..options
+                }
+            ),
+            Ok("three milliard".to_string())
+        );
+        assert_eq!(
+            number_to_words_u128(
+                1_000,
+                &Options {
+                    omit_one_thousand: true,
+                    ..options
+                }
+            ),
+            Ok("one thousand".to_string())
+        );
+    }
+
+    #[cfg(feature = "words-to-number")]
+    #[test]
+    fn round_trip_test() {
+        use crate::words_to_number::words_to_number;
+
+        for number in [i64::MIN, -2003, -1, 0, 2_003, i64::MAX] {
+            let words = number_to_words(number).unwrap();
+            assert_eq!(words_to_number(&words), Ok(number), "{words}");
+        }
+    }
+
+    #[cfg(feature = "words-to-number")]
+    #[test]
+    fn long_scale_round_trip_test() {
+        use crate::words_to_number::words_to_number_with_scale;
+
+        for language in [Language::Persian, Language::English] {
+            let options = Options {
+                scale: Scale::Long,
+                language,
+                ..Default::default()
+            };
+            for number in [
+                1_000_000_000,
+                1_000_000_000_000,
+                2_000_003_000_000_000,
+                1_000_000_000_000_000_000,
+                i64::MAX as u128,
+            ] {
+                let words = number_to_words_u128(number, &options).unwrap();
+                assert_eq!(
+                    words_to_number_with_scale(&words, Scale::Long),
+                    Ok(number as i64),
+                    "{words}"
+                );
+            }
+        }
+    }
+
+    #[cfg(feature = "words-to-number")]
+    #[test]
+    fn english_round_trip_test() {
+        use crate::words_to_number::words_to_number;
+
+        let options = Options {
+            language: Language::English,
+            ..Default::default()
+        };
+        for number in [1, 15, 99, 100, 119, 1_000, 21_300, 1_000_001, 987_654_321] {
+            let words = number_to_words_u128(number, &options).unwrap();
+            assert_eq!(words_to_number(&words), Ok(number as i64), "{words}");
+        }
+    }
+
+    #[cfg(feature = "words-to-number")]
+    #[test]
+    fn style_round_trip_test() {
+        use crate::words_to_number::words_to_number;
+
+        let styles = [Hundreds::Spaced, Hundreds::Joined, Hundreds::HalfSpace];
+        for hundreds in styles {
+            for flags in 0..4 {
+                let options = Options {
+                    hundreds,
+                    explicit_one_hundred: flags & 1 != 0,
+                    omit_one_thousand: flags & 2 != 0,
+                    ..Default::default()
+                };
+                for number in [
+                    100,
+                    101,
+                    400,
+                    1_000,
+                    1_100,
+                    1_999,
+                    17_600,
+                    1_001_000,
+                    1_200_000,
+                    987_654_321,
+                    100_100_100_100,
+                ] {
+                    let words = number_to_words_u128(number, &options).unwrap();
+                    assert_eq!(words_to_number(&words), Ok(number as i64), "{words}");
+                }
+            }
+        }
+    }
+
     #[test]
     fn number_to_word_str_test() {
         assert_eq!(number_to_words_str("33"), Ok("سی و سه".to_string()));
@@ -131,6 +529,19 @@ mod tests {
             number_to_words_str("500,443"),
             Ok("پانصد هزار و چهار صد و چهل و سه".to_string())
         );
+        assert_eq!(
+            number_to_words_str("3.14"),
+            Ok("سه و چهارده صدم".to_string())
+        );
+        assert_eq!(
+            number_to_words_str("-۱٬۲۵۰٫۷۵"),
+            Ok("منفی یک هزار و دویست و پنجاه و هفتاد و پنج صدم".to_string())
+        );
+        assert_eq!(number_to_words_str("١ / ٣"), Ok("یک سوم".to_string()));
+        assert_eq!(
+            number_to_words_str("12.5%"),
+            Ok("دوازده و پنج دهم درصد".to_string())
+        );
     }
 
     #[test]
@@ -145,8 +556,16 @@ mod tests {
             Err(NumberToWordsError::InvalidInteger("123abc".to_string()))
         );
         assert_eq!(
-            number_to_words_str("3.14"),
-            Err(NumberToWordsError::InvalidInteger("3.14".to_string()))
+            number_to_words_str("3.14.15"),
+            Err(NumberToWordsError::InvalidInteger("3.14.15".to_string()))
+        );
+        assert_eq!(
+            number_to_words_str("1/0"),
+            Err(NumberToWordsError::ZeroDenominator)
+        );
+        assert_eq!(
+            number_to_words_str("%"),
+            Err(NumberToWordsError::EmptyString)
         );
         let long = "9999999999999999999999999999999999999999999999999";
         assert_eq!(
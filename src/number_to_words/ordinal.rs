@@ -0,0 +1,135 @@
+use crate::add_ordinal_suffix::add_ordinal_suffix;
+
+use super::error::NumberToWordsError;
+use super::number_to_words;
+
+/// Form of the ordinal suffix
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(PartialEq, Eq, Debug, Clone, Copy, Hash, Default)]
+pub enum OrdinalForm {
+    /// "بیست و یکم", used after the noun: "روز بیست و یکم"
+    #[default]
+    Suffix,
+    /// "بیست و یکمین", used before the noun: "بیست و یکمین روز"
+    Adjective,
+}
+
+/// Word that is used for the number one itself\
+/// Numbers ending in one such as 21 always use "یکم"
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(PartialEq, Eq, Debug, Clone, Copy, Hash, Default)]
+pub enum FirstWord {
+    /// "یکم" and "یکمین"
+    #[default]
+    Yekom,
+    /// "اول" and "اولین"
+    Aval,
+    /// "نخست" and "نخستین"
+    Nakhost,
+}
+
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(PartialEq, Eq, Debug, Clone, Copy, Hash, Default)]
+pub struct OrdinalOptions {
+    pub form: OrdinalForm,
+    pub first: FirstWord,
+}
+
+/// Convert a non-negative integer to persian ordinal words.
+/// ```
+/// # use rust_persian_tools::number_to_words::{
+/// #     number_to_ordinal_words, FirstWord, OrdinalForm, OrdinalOptions,
+/// # };
+/// let options = OrdinalOptions::default();
+/// assert_eq!(number_to_ordinal_words(21, &options), Ok("بیست و یکم".to_string()));
+/// assert_eq!(number_to_ordinal_words(3, &options), Ok("سوم".to_string()));
+///
+/// let options = OrdinalOptions {
+///     form: OrdinalForm::Adjective,
+///     first: FirstWord::Nakhost,
+/// };
+/// assert_eq!(number_to_ordinal_words(1, &options), Ok("نخستین".to_string()));
+/// assert_eq!(number_to_ordinal_words(21, &options), Ok("بیست و یکمین".to_string()));
+/// assert!(number_to_ordinal_words(-1, &options).is_err());
+/// ```
+pub fn number_to_ordinal_words(
+    input: i64,
+    options: &OrdinalOptions,
+) -> Result<String, NumberToWordsError> {
+    if input < 0 {
+        return Err(NumberToWordsError::InvalidInteger(input.to_string()));
+    }
+
+    let words = match (input, options.first) {
+        (1, FirstWord::Aval) => "اول".to_string(),
+        (1, FirstWord::Nakhost) => "نخست".to_string(),
+        (input, _) => add_ordinal_suffix(number_to_words(input)?),
+    };
+
+    match options.form {
+        OrdinalForm::Suffix => Ok(words),
+        OrdinalForm::Adjective => Ok(format!("{words}ین")),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn ordinal_test() {
+        let options = OrdinalOptions::default();
+        assert_eq!(number_to_ordinal_words(0, &options), Ok("صفرم".to_string()));
+        assert_eq!(number_to_ordinal_words(1, &options), Ok("یکم".to_string()));
+        assert_eq!(
+            number_to_ordinal_words(30, &options),
+            Ok("سی اُم".to_string())
+        );
+        assert_eq!(
+            number_to_ordinal_words(33, &options),
+            Ok("سی و سوم".to_string())
+        );
+        assert_eq!(
+            number_to_ordinal_words(1000, &options),
+            Ok("یک هزارم".to_string())
+        );
+        assert_eq!(
+            number_to_ordinal_words(-5, &options),
+            Err(NumberToWordsError::InvalidInteger("-5".to_string()))
+        );
+    }
+
+    #[test]
+    fn ordinal_options_test() {
+        let aval = OrdinalOptions {
+            form: OrdinalForm::Suffix,
+            first: FirstWord::Aval,
+        };
+        assert_eq!(number_to_ordinal_words(1, &aval), Ok("اول".to_string()));
+        assert_eq!(
+            number_to_ordinal_words(101, &aval),
+            Ok("صد و یکم".to_string())
+        );
+
+        let adjective = OrdinalOptions {
+            form: OrdinalForm::Adjective,
+            ..aval
+        };
+        assert_eq!(
+            number_to_ordinal_words(1, &adjective),
+            Ok("اولین".to_string())
+        );
+        assert_eq!(
+            number_to_ordinal_words(3, &adjective),
+            Ok("سومین".to_string())
+        );
+        assert_eq!(
+            number_to_ordinal_words(40, &adjective),
+            Ok("چهلمین".to_string())
+        );
+        assert_eq!(
+            number_to_ordinal_words(30, &adjective),
+            Ok("سی اُمین".to_string())
+        );
+    }
+}
@@ -0,0 +1,133 @@
+use crate::digits::Language;
+
+/// Names of 10^(3n+3) in short scale, starting from million
+const ILLIONS: [&str; 10] = [
+    "میلیون",
+    "بیلیون",
+    "تریلیون",
+    "کوآدریلیون",
+    "کوینتیلیون",
+    "سکستیلیون",
+    "سپتیلیون",
+    "اکتیلیون",
+    "نونیلیون",
+    "دسیلیون",
+];
+
+const ILLIONS_EN: [&str; 10] = [
+    "million",
+    "billion",
+    "trillion",
+    "quadrillion",
+    "quintillion",
+    "sextillion",
+    "septillion",
+    "octillion",
+    "nonillion",
+    "decillion",
+];
+
+/// Naming of large numbers
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(PartialEq, Eq, Debug, Clone, Copy, Hash, Default)]
+pub enum Scale {
+    /// Common usage in Iran: short scale with "میلیارد" for 10^9\
+    /// هزار، میلیون، میلیارد، تریلیون، کوآدریلیون، ...
+    #[default]
+    Iranian,
+    /// هزار، میلیون، بیلیون، تریلیون، کوآدریلیون، ...
+    Short,
+    /// هزار، میلیون، میلیارد، بیلیون، بیلیارد، تریلیون، ...
+    Long,
+}
+
+impl Scale {
+    /// Returns name of 10^(3 * `group`) or [None] if it is not in the table\
+    /// Group 0 has no name. Only Persian has a name for 10^9 in the Iranian scale,
+    /// so English names of the Iranian scale are the same as the short scale.
+    fn table_name(&self, group: usize, language: Language) -> Option<String> {
+        let english = language == Language::English;
+        let illions = if english { &ILLIONS_EN } else { &ILLIONS };
+
+        match (self, group) {
+            (_, 0) => Some(String::new()),
+            (_, 1) if english => Some("thousand".to_string()),
+            (_, 1) => Some("هزار".to_string()),
+            (Scale::Iranian, 3) if !english => Some("میلیارد".to_string()),
+            (Scale::Iranian | Scale::Short, group) => illions.get(group - 2).map(|s| s.to_string()),
+            (Scale::Long, group) if group % 2 == 0 => {
+                illions.get(group / 2 - 1).map(|s| s.to_string())
+            }
+            (Scale::Long, group) => illions.get(group / 2 - 1).map(|illion| {
+                if english {
+                    illion.replace("illion", "illiard")
+                } else {
+                    illion.replace("یون", "یارد")
+                }
+            }),
+        }
+    }
+
+    /// Largest group that has a name in the table
+    fn max_group(&self) -> usize {
+        match self {
+            Scale::Iranian | Scale::Short => ILLIONS.len() + 1,
+            Scale::Long => ILLIONS.len() * 2 + 1,
+        }
+    }
+
+    /// Returns name of 10^(3 * `group`) in `language`, names beyond the table are composed
+    /// e.g. "هزار دسیلیون" in short scale\
+    /// Arabic is named the same as Persian
+    pub(super) fn name(&self, group: usize, language: Language) -> String {
+        match self.table_name(group, language) {
+            Some(name) => name,
+            None => {
+                let max_group = self.max_group();
+                format!(
+                    "{} {}",
+                    self.name(group - max_group, language),
+                    self.name(max_group, language)
+                )
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn name_test() {
+        assert_eq!(Scale::Iranian.name(0, Language::Persian), "");
+        assert_eq!(Scale::Iranian.name(1, Language::Persian), "هزار");
+        assert_eq!(Scale::Iranian.name(3, Language::Persian), "میلیارد");
+        assert_eq!(Scale::Iranian.name(4, Language::Persian), "تریلیون");
+        assert_eq!(Scale::Short.name(3, Language::Persian), "بیلیون");
+        assert_eq!(Scale::Short.name(11, Language::Persian), "دسیلیون");
+        assert_eq!(Scale::Short.name(12, Language::Persian), "هزار دسیلیون");
+        assert_eq!(
+            Scale::Short.name(23, Language::Persian),
+            "هزار دسیلیون دسیلیون"
+        );
+        assert_eq!(Scale::Long.name(3, Language::Persian), "میلیارد");
+        assert_eq!(Scale::Long.name(4, Language::Persian), "بیلیون");
+        assert_eq!(Scale::Long.name(5, Language::Persian), "بیلیارد");
+        assert_eq!(Scale::Long.name(21, Language::Persian), "دسیلیارد");
+        assert_eq!(Scale::Long.name(22, Language::Persian), "هزار دسیلیارد");
+    }
+
+    #[test]
+    fn english_name_test() {
+        assert_eq!(Scale::Iranian.name(1, Language::English), "thousand");
+        assert_eq!(Scale::Iranian.name(3, Language::English), "billion");
+        assert_eq!(
+            Scale::Short.name(12, Language::English),
+            "thousand decillion"
+        );
+        assert_eq!(Scale::Long.name(3, Language::English), "milliard");
+        assert_eq!(Scale::Long.name(4, Language::English), "billion");
+        assert_eq!(Scale::Long.name(3, Language::Arabic), "میلیارد");
+    }
+}
@@ -1,9 +1,13 @@
 use crate::number_to_words::error::NumberToWordsError;
 
 use super::get_word::get_defined_word;
+use super::Options;
 
 /// Converts number to words for (0..1000)
-pub(super) fn three_digit_number_to_words(num: u64) -> Result<String, NumberToWordsError> {
+pub(super) fn three_digit_number_to_words(
+    num: u64,
+    options: &Options,
+) -> Result<String, NumberToWordsError> {
     if num > 999 {
         return Err(NumberToWordsError::Internal);
     }
@@ -12,11 +16,25 @@ pub(super) fn three_digit_number_to_words(num: u64) -> Result<String, NumberToWo
         return Ok("".to_string());
     }
 
+    // 100, 200, ...
+    if num % 100 == 0 {
+        return hundreds_word(num, options);
+    }
+
     // already on list
     if let Ok(c) = get_defined_word(num) {
         return Ok(c.to_string());
     }
 
+    // 11 to 19 are single words, example: 211
+    if (11..=19).contains(&(num % 100)) {
+        return Ok(format!(
+            "{} و {}",
+            hundreds_word(num - num % 100, options)?,
+            get_defined_word(num % 100)?
+        ));
+    }
+
     let binding = digits_with_zeros(num);
     let mut digits = binding.iter();
 
@@ -32,17 +50,25 @@ pub(super) fn three_digit_number_to_words(num: u64) -> Result<String, NumberToWo
             match (d1, d2, d3) {
                 (d1, 0, d3) => {
                     // example: 304
-                    format!("{} و {}", get_defined_word(*d3)?, get_defined_word(*d1)?)
+                    format!(
+                        "{} و {}",
+                        hundreds_word(*d3, options)?,
+                        get_defined_word(*d1)?
+                    )
                 }
                 (0, d2, d3) => {
                     // example: 340
-                    format!("{} و {}", get_defined_word(*d3)?, get_defined_word(*d2)?)
+                    format!(
+                        "{} و {}",
+                        hundreds_word(*d3, options)?,
+                        get_defined_word(*d2)?
+                    )
                 }
                 (d1, d2, d3) => {
                     // example: 345
                     format!(
                         "{} و {} و {}",
-                        get_defined_word(*d3)?,
+                        hundreds_word(*d3, options)?,
                         get_defined_word(*d2)?,
                         get_defined_word(*d1)?
                     )
@@ -53,6 +79,22 @@ pub(super) fn three_digit_number_to_words(num: u64) -> Result<String, NumberToWo
     })
 }
 
+/// hundreds_word(400) -> "چهار صد", "چهارصد" or "چهار‌صد" based on [Options::hundreds]
+fn hundreds_word(num: u64, options: &Options) -> Result<String, NumberToWordsError> {
+    let prefix = match num {
+        100 if options.explicit_one_hundred => "یک",
+        400 => "چهار",
+        600 => "شش",
+        700 => "هفت",
+        800 => "هشت",
+        900 => "نه",
+        // صد, دویست, سیصد and پانصد are always single words
+        num => return Ok(get_defined_word(num)?.to_string()),
+    };
+
+    Ok(format!("{}{}صد", prefix, options.hundreds.separator()))
+}
+
 /// digits_with_zeros(123) -> [3, 20, 100]
 fn digits_with_zeros(num: u64) -> Vec<u64> {
     let mut num = num;
@@ -78,30 +120,90 @@ fn number_with_zeros(number: u64, zeros: u32) -> u64 {
 
 #[cfg(test)]
 mod tests {
+    use super::super::Hundreds;
     use super::*;
 
     #[test]
     fn number_to_words_three_digits() {
-        assert_eq!(three_digit_number_to_words(0), Ok("".to_string()));
-        assert_eq!(three_digit_number_to_words(4), Ok("چهار".to_string()));
-        assert_eq!(three_digit_number_to_words(33), Ok("سی و سه".to_string()));
-        assert_eq!(three_digit_number_to_words(30), Ok("سی".to_string()));
+        let options = Options::default();
+        assert_eq!(three_digit_number_to_words(0, &options), Ok("".to_string()));
+        assert_eq!(
+            three_digit_number_to_words(4, &options),
+            Ok("چهار".to_string())
+        );
+        assert_eq!(
+            three_digit_number_to_words(33, &options),
+            Ok("سی و سه".to_string())
+        );
+        assert_eq!(
+            three_digit_number_to_words(30, &options),
+            Ok("سی".to_string())
+        );
         assert_eq!(
-            three_digit_number_to_words(303),
+            three_digit_number_to_words(303, &options),
             Ok("سیصد و سه".to_string())
         );
         assert_eq!(
-            three_digit_number_to_words(123),
+            three_digit_number_to_words(123, &options),
             Ok("صد و بیست و سه".to_string())
         );
-        assert_eq!(three_digit_number_to_words(103), Ok("صد و سه".to_string()));
+        assert_eq!(
+            three_digit_number_to_words(103, &options),
+            Ok("صد و سه".to_string())
+        );
+        assert_eq!(
+            three_digit_number_to_words(211, &options),
+            Ok("دویست و یازده".to_string())
+        );
+        assert_eq!(
+            three_digit_number_to_words(919, &options),
+            Ok("نه صد و نوزده".to_string())
+        );
 
         // error check
         for i in 0..1000 {
-            three_digit_number_to_words(i).unwrap();
+            three_digit_number_to_words(i, &options).unwrap();
         }
     }
 
+    #[test]
+    fn hundreds_style_test() {
+        let joined = Options {
+            hundreds: Hundreds::Joined,
+            ..Default::default()
+        };
+        assert_eq!(
+            three_digit_number_to_words(919, &joined),
+            Ok("نهصد و نوزده".to_string())
+        );
+        assert_eq!(
+            three_digit_number_to_words(600, &joined),
+            Ok("ششصد".to_string())
+        );
+        assert_eq!(
+            three_digit_number_to_words(100, &joined),
+            Ok("صد".to_string())
+        );
+
+        let half_space = Options {
+            hundreds: Hundreds::HalfSpace,
+            explicit_one_hundred: true,
+            ..Default::default()
+        };
+        assert_eq!(
+            three_digit_number_to_words(105, &half_space),
+            Ok("یک\u{200c}صد و پنج".to_string())
+        );
+        assert_eq!(
+            three_digit_number_to_words(740, &half_space),
+            Ok("هفت\u{200c}صد و چهل".to_string())
+        );
+        assert_eq!(
+            three_digit_number_to_words(200, &half_space),
+            Ok("دویست".to_string())
+        );
+    }
+
     #[test]
     fn number_with_zeros_test() {
         assert_eq!(number_with_zeros(4, 5), 400000);
@@ -0,0 +1,372 @@
+use chrono::Duration;
+
+use super::TimeDiff;
+use crate::digits::{en_to_language, Language};
+
+/// Units that a [TimeDiff] is rendered with, from the smallest to the largest
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(PartialEq, Eq, PartialOrd, Ord, Debug, Clone, Copy, Hash)]
+pub enum TimeUnit {
+    Second,
+    Minute,
+    Hour,
+    Day,
+    Week,
+    Month,
+    Year,
+}
+
+impl TimeUnit {
+    pub fn word(&self) -> &'static str {
+        match self {
+            TimeUnit::Second => "ثانیه",
+            TimeUnit::Minute => "دقیقه",
+            TimeUnit::Hour => "ساعت",
+            TimeUnit::Day => "روز",
+            TimeUnit::Week => "هفته",
+            TimeUnit::Month => "ماه",
+            TimeUnit::Year => "سال",
+        }
+    }
+}
+
+/// Words that are used for past, future and now
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(PartialEq, Eq, Debug, Clone, Copy, Hash, Default)]
+pub enum Wording {
+    /// "قبل", "بعد" and "اکنون"
+    #[default]
+    Standard,
+    /// "پیش", "دیگر" and "همین الان"
+    Alternative,
+}
+
+impl Wording {
+    pub fn past(&self) -> &'static str {
+        match self {
+            Wording::Standard => "قبل",
+            Wording::Alternative => "پیش",
+        }
+    }
+
+    pub fn future(&self) -> &'static str {
+        match self {
+            Wording::Standard => "بعد",
+            Wording::Alternative => "دیگر",
+        }
+    }
+
+    pub fn now(&self) -> &'static str {
+        match self {
+            Wording::Standard => "اکنون",
+            Wording::Alternative => "همین الان",
+        }
+    }
+}
+
+/// Configurable renderer of [TimeDiff]
+///
+/// By default all non-zero units from years to seconds are rendered with English digits,
+/// same as [TimeDiff::long_form].
+///
+/// # Examples
+///
+/// ```
+/// use rust_persian_tools::digits::Language;
+/// use rust_persian_tools::time_diff::{TimeDiff, TimeDiffFormatter, TimeUnit, Wording};
+///
+/// let diff = TimeDiff {
+///     years: 0,
+///     months: 0,
+///     days: 10,
+///     hours: 5,
+///     minutes: 30,
+///     seconds: 12,
+///     is_future: false,
+/// };
+///
+/// assert_eq!(
+///     TimeDiffFormatter::new().format(&diff),
+///     "10 روز و 5 ساعت و 30 دقیقه و 12 ثانیه قبل"
+/// );
+///
+/// let formatter = TimeDiffFormatter::new()
+///     .max_units(2)
+///     .weeks(true)
+///     .wording(Wording::Alternative)
+///     .digits(Language::Persian);
+/// assert_eq!(formatter.format(&diff), "۱ هفته و ۳ روز پیش");
+///
+/// let formatter = TimeDiffFormatter::new().min_unit(TimeUnit::Minute).approximate(true);
+/// assert_eq!(formatter.format(&diff), "حدود 10 روز و 5 ساعت و 30 دقیقه قبل");
+/// ```
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(PartialEq, Eq, Debug, Clone, Copy, Hash)]
+pub struct TimeDiffFormatter {
+    max_units: usize,
+    min_unit: TimeUnit,
+    weeks: bool,
+    wording: Wording,
+    now_threshold: i64,
+    approximate: bool,
+    digits: Language,
+    #[cfg(feature = "number-to-words")]
+    spell_numbers: bool,
+}
+
+impl Default for TimeDiffFormatter {
+    fn default() -> Self {
+        Self {
+            max_units: usize::MAX,
+            min_unit: TimeUnit::Second,
+            weeks: false,
+            wording: Wording::default(),
+            now_threshold: 0,
+            approximate: false,
+            digits: Language::English,
+            #[cfg(feature = "number-to-words")]
+            spell_numbers: false,
+        }
+    }
+}
+
+impl TimeDiffFormatter {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Maximum number of non-zero units to render, starting from the largest one\
+    /// `0` is treated as `1`
+    pub fn max_units(mut self, max_units: usize) -> Self {
+        self.max_units = max_units.max(1);
+        self
+    }
+
+    /// Smallest unit to render, smaller units are dropped
+    pub fn min_unit(mut self, min_unit: TimeUnit) -> Self {
+        self.min_unit = min_unit;
+        self
+    }
+
+    /// Render 7 days and more as weeks
+    pub fn weeks(mut self, weeks: bool) -> Self {
+        self.weeks = weeks;
+        self
+    }
+
+    pub fn wording(mut self, wording: Wording) -> Self {
+        self.wording = wording;
+        self
+    }
+
+    /// Differences shorter than `threshold` are rendered as now
+    pub fn now_threshold(mut self, threshold: Duration) -> Self {
+        self.now_threshold = threshold.num_seconds();
+        self
+    }
+
+    /// Prefix the result with "حدود" when some non-zero units are dropped
+    pub fn approximate(mut self, approximate: bool) -> Self {
+        self.approximate = approximate;
+        self
+    }
+
+    /// Script of the digits
+    pub fn digits(mut self, digits: Language) -> Self {
+        self.digits = digits;
+        self
+    }
+
+    /// Render quantities as Persian words instead of digits, e.g. "شش ماه" instead of "6 ماه"
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rust_persian_tools::time_diff::{TimeDiff, TimeDiffFormatter};
+    ///
+    /// let diff = TimeDiff {
+    ///     years: 6,
+    ///     months: 6,
+    ///     days: 0,
+    ///     hours: 0,
+    ///     minutes: 0,
+    ///     seconds: 21,
+    ///     is_future: false,
+    /// };
+    /// assert_eq!(
+    ///     TimeDiffFormatter::new().spell_numbers(true).format(&diff),
+    ///     "شش سال و شش ماه و بیست و یک ثانیه قبل"
+    /// );
+    /// ```
+    #[cfg(feature = "number-to-words")]
+    pub fn spell_numbers(mut self, spell_numbers: bool) -> Self {
+        self.spell_numbers = spell_numbers;
+        self
+    }
+
+    pub fn format(&self, diff: &TimeDiff) -> String {
+        let (weeks, days) = if self.weeks {
+            (diff.days / 7, diff.days % 7)
+        } else {
+            (0, diff.days)
+        };
+        let units = [
+            (TimeUnit::Year, diff.years),
+            (TimeUnit::Month, diff.months as u32),
+            (TimeUnit::Week, weeks as u32),
+            (TimeUnit::Day, days as u32),
+            (TimeUnit::Hour, diff.hours as u32),
+            (TimeUnit::Minute, diff.minutes as u32),
+            (TimeUnit::Second, diff.seconds as u32),
+        ];
+
+        let non_zero = units
+            .iter()
+            .filter(|(_, value)| *value > 0)
+            .collect::<Vec<_>>();
+        let shown = non_zero
+            .iter()
+            .take_while(|(unit, _)| *unit >= self.min_unit)
+            .take(self.max_units)
+            .collect::<Vec<_>>();
+
+        if shown.is_empty() || diff.to_duration().num_seconds().abs() < self.now_threshold {
+            return self.wording.now().to_owned();
+        }
+
+        let periods = shown
+            .iter()
+            .map(|(unit, value)| format!("{} {}", self.quantity(*value), unit.word()))
+            .collect::<Vec<_>>()
+            .join(" و ");
+        let pre_or_next = if diff.is_future {
+            self.wording.future()
+        } else {
+            self.wording.past()
+        };
+
+        let result = if self.approximate && shown.len() < non_zero.len() {
+            format!("حدود {} {}", periods, pre_or_next)
+        } else {
+            format!("{} {}", periods, pre_or_next)
+        };
+
+        en_to_language(result, self.digits)
+    }
+
+    fn quantity(&self, value: u32) -> String {
+        #[cfg(feature = "number-to-words")]
+        if self.spell_numbers {
+            // u32 always fits in i64, so this never fails
+            if let Ok(words) = crate::number_to_words::number_to_words(value as i64) {
+                return words;
+            }
+        }
+
+        value.to_string()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn diff(days: u8, hours: u8, minutes: u8, seconds: u8) -> TimeDiff {
+        TimeDiff {
+            years: 0,
+            months: 0,
+            days,
+            hours,
+            minutes,
+            seconds,
+            is_future: true,
+        }
+    }
+
+    #[test]
+    fn default_test() {
+        let formatter = TimeDiffFormatter::default();
+
+        assert_eq!(formatter.format(&diff(0, 1, 0, 5)), "1 ساعت و 5 ثانیه بعد");
+        assert_eq!(formatter.format(&diff(0, 0, 0, 0)), "اکنون");
+        assert_eq!(
+            formatter.format(&diff(3, 2, 1, 0)),
+            diff(3, 2, 1, 0).long_form()
+        );
+    }
+
+    #[test]
+    fn units_test() {
+        let formatter = TimeDiffFormatter::new().max_units(1);
+        assert_eq!(formatter.format(&diff(15, 2, 0, 0)), "15 روز بعد");
+        assert_eq!(
+            formatter.weeks(true).format(&diff(15, 2, 0, 0)),
+            "2 هفته بعد"
+        );
+        assert_eq!(
+            TimeDiffFormatter::new()
+                .weeks(true)
+                .format(&diff(14, 0, 0, 0)),
+            "2 هفته بعد"
+        );
+
+        let formatter = TimeDiffFormatter::new().min_unit(TimeUnit::Hour);
+        assert_eq!(formatter.format(&diff(1, 0, 5, 3)), "1 روز بعد");
+        assert_eq!(formatter.format(&diff(0, 0, 59, 59)), "اکنون");
+        assert_eq!(
+            formatter.approximate(true).format(&diff(0, 2, 1, 0)),
+            "حدود 2 ساعت بعد"
+        );
+        assert_eq!(
+            TimeDiffFormatter::new()
+                .max_units(0)
+                .format(&diff(1, 1, 0, 0)),
+            "1 روز بعد"
+        );
+    }
+
+    #[test]
+    fn wording_test() {
+        let formatter = TimeDiffFormatter::new()
+            .wording(Wording::Alternative)
+            .now_threshold(Duration::try_minutes(1).unwrap());
+
+        assert_eq!(formatter.format(&diff(0, 0, 0, 59)), "همین الان");
+        assert_eq!(formatter.format(&diff(0, 0, 1, 0)), "1 دقیقه دیگر");
+        assert_eq!(
+            formatter.format(&TimeDiff {
+                is_future: false,
+                ..diff(0, 0, 1, 0)
+            }),
+            "1 دقیقه پیش"
+        );
+        assert_eq!(
+            formatter
+                .digits(Language::Arabic)
+                .format(&diff(0, 0, 12, 0)),
+            "١٢ دقیقه دیگر"
+        );
+    }
+
+    #[cfg(feature = "number-to-words")]
+    #[test]
+    fn spell_numbers_test() {
+        let formatter = TimeDiffFormatter::new().spell_numbers(true);
+
+        assert_eq!(
+            formatter.format(&diff(12, 1, 0, 0)),
+            "دوازده روز و یک ساعت بعد"
+        );
+        assert_eq!(
+            formatter
+                .weeks(true)
+                .wording(Wording::Alternative)
+                .format(&TimeDiff {
+                    years: 1402,
+                    ..diff(14, 0, 0, 0)
+                }),
+            "یک هزار و چهار صد و دو سال و دو هفته دیگر"
+        );
+        assert_eq!(formatter.format(&diff(0, 0, 0, 0)), "اکنون");
+    }
+}
@@ -1,7 +1,18 @@
-use chrono::{DateTime, Local, NaiveDateTime, TimeZone};
+use chrono::{
+    DateTime, Datelike, Duration, FixedOffset, Local, Months, NaiveDateTime, TimeZone, Utc,
+};
 use thiserror::Error;
 
-use crate::digits::{DigitsEn2Ar, DigitsEn2Fa};
+use crate::digits::{DigitsAr2En, DigitsEn2Ar, DigitsEn2Fa, DigitsFa2En};
+use crate::jalali::{JalaliDate, JalaliDateTime};
+
+mod formatter;
+#[cfg(feature = "words-to-number")]
+mod parse;
+
+pub use formatter::{TimeDiffFormatter, TimeUnit, Wording};
+#[cfg(feature = "words-to-number")]
+pub use parse::{parse_relative, parse_relative_duration, RelativeTimeError};
 
 pub(crate) const MINUTE: i64 = 60;
 pub(crate) const HOUR: i64 = MINUTE * 60;
@@ -9,6 +20,9 @@ pub(crate) const DAY: i64 = HOUR * 24;
 pub(crate) const MONTH: i64 = DAY * 30;
 pub(crate) const YEAR: i64 = DAY * 365;
 
+/// Datetime strings with a year before this are considered Jalali when calendar is auto-detected
+const JALALI_YEAR_THRESHOLD: i32 = 1700;
+
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[derive(Error, Clone, Copy, Debug, Hash, PartialEq, Eq)]
 pub enum TimeAgoError {
@@ -43,6 +57,40 @@ impl From<i64> for Timestamp {
     }
 }
 
+/// Calendar of a datetime string
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Clone, Copy, Debug, Hash, PartialEq, Eq)]
+pub enum Calendar {
+    Gregorian,
+    /// Solar Hijri (Shamsi), see [crate::jalali]
+    Jalali,
+}
+
+impl Calendar {
+    /// Detects calendar of a datetime string by its year\
+    /// Datetime strings starting with a year before 1700 are considered [Calendar::Jalali]
+    /// ```
+    /// use rust_persian_tools::time_diff::Calendar;
+    ///
+    /// assert_eq!(Calendar::detect("۱۴۰۳/۰۷/۲۶ ۱۴:۳۰"), Calendar::Jalali);
+    /// assert_eq!(Calendar::detect("2024/10/17 14:30:00"), Calendar::Gregorian);
+    /// assert_eq!(Calendar::detect("Thu, 17 Oct 2024 14:30:00 +0330"), Calendar::Gregorian);
+    /// ```
+    pub fn detect(datetime: impl AsRef<str>) -> Self {
+        let datetime = datetime.as_ref().digits_fa_to_en().digits_ar_to_en();
+        let year = datetime
+            .trim()
+            .split(|c: char| !c.is_ascii_digit())
+            .next()
+            .and_then(|year| year.parse::<i32>().ok());
+
+        match year {
+            Some(year) if year < JALALI_YEAR_THRESHOLD => Calendar::Jalali,
+            _ => Calendar::Gregorian,
+        }
+    }
+}
+
 /// The [TimeDiff] stuct has two main methods: `short_form()` & `long_form()` \
 /// the `short_form()` returns a short desciption about time diffrence\
 /// - 5 دقیقه قبل
@@ -56,6 +104,8 @@ impl From<i64> for Timestamp {
 /// - short_form_ar_digits()
 /// - long_form_fa_digits()
 /// - long_form_ar_digits()
+///
+/// For other renderings use [TimeDiffFormatter]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[derive(PartialEq, Eq, Debug, Clone, Copy, Hash)]
 pub struct TimeDiff {
@@ -69,6 +119,93 @@ pub struct TimeDiff {
 }
 
 impl TimeDiff {
+    /// Returns the exact difference between two instants by walking actual calendar boundaries\
+    /// instead of considering 30 days for a month and 365 days for a year.\
+    /// Leap years and 29/30/31-day months of the given [Calendar] are respected.
+    ///
+    /// When adding months lands on a day that does not exist (e.g. 31st of a 30-day month),
+    /// the last day of that month is used.
+    ///
+    /// Returns `None` if a month boundary can't be computed, e.g. when the dates are out of
+    /// the supported range of [JalaliDate] with [Calendar::Jalali].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rust_persian_tools::time_diff::{Calendar, TimeDiff};
+    /// use chrono::{TimeZone, Utc};
+    ///
+    /// let start = Utc.with_ymd_and_hms(2020, 1, 1, 0, 0, 0).unwrap();
+    /// let end = Utc.with_ymd_and_hms(2023, 4, 6, 3, 13, 37).unwrap();
+    /// assert_eq!(
+    ///     TimeDiff::calendar_between(&start, &end, Calendar::Gregorian).unwrap().long_form(),
+    ///     "3 سال و 3 ماه و 5 روز و 3 ساعت و 13 دقیقه و 37 ثانیه بعد"
+    /// );
+    ///
+    /// // 1403/06/31 -> 1403/07/30
+    /// let start = Utc.with_ymd_and_hms(2024, 9, 21, 0, 0, 0).unwrap();
+    /// let end = Utc.with_ymd_and_hms(2024, 10, 21, 0, 0, 0).unwrap();
+    /// assert_eq!(
+    ///     TimeDiff::calendar_between(&end, &start, Calendar::Jalali).unwrap().long_form(),
+    ///     "1 ماه قبل"
+    /// );
+    /// ```
+    pub fn calendar_between<Tz: TimeZone>(
+        start: &DateTime<Tz>,
+        end: &DateTime<Tz>,
+        calendar: Calendar,
+    ) -> Option<Self> {
+        let is_future = end > start;
+        let (from, to) = if is_future {
+            (start.naive_local(), end.naive_local())
+        } else {
+            (end.naive_local(), start.naive_local())
+        };
+
+        let add_months = |months: u32| -> Option<NaiveDateTime> {
+            match calendar {
+                Calendar::Gregorian => from.checked_add_months(Months::new(months)),
+                Calendar::Jalali => Some(
+                    JalaliDate::from_gregorian(from.date())
+                        .ok()?
+                        .checked_add_months(months as i32)?
+                        .to_gregorian()
+                        .and_time(from.time()),
+                ),
+            }
+        };
+
+        let mut months = match calendar {
+            Calendar::Gregorian => {
+                (to.year() - from.year()) * 12 + to.month() as i32 - from.month() as i32
+            }
+            Calendar::Jalali => {
+                let from = JalaliDate::from_gregorian(from.date()).ok()?;
+                let to = JalaliDate::from_gregorian(to.date()).ok()?;
+                (to.year() - from.year()) * 12 + to.month() as i32 - from.month() as i32
+            }
+        }
+        .max(0) as u32;
+
+        let mut boundary = add_months(months);
+        while months > 0 && boundary.is_none_or(|boundary| boundary > to) {
+            months -= 1;
+            boundary = add_months(months);
+        }
+        // `to` is at most two months after `boundary`, so days fit in u8
+        let rest = (to - boundary?).num_seconds();
+
+        Some(TimeDiff {
+            years: months / 12,
+            months: (months % 12) as u8,
+            days: (rest / DAY) as u8,
+            hours: (rest % DAY / HOUR) as u8,
+            minutes: (rest % HOUR / MINUTE) as u8,
+            seconds: (rest % MINUTE) as u8,
+            is_future,
+        })
+    }
+
     pub fn long_form(&self) -> String {
         let mut periods: Vec<String> = Vec::new();
 
@@ -134,6 +271,71 @@ impl TimeDiff {
         self.long_form().digits_en_to_ar()
     }
 
+    /// Same as [TimeDiff::long_form] but quantities are written as Persian words
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rust_persian_tools::time_diff::TimeDiff;
+    ///
+    /// let diff = TimeDiff {
+    ///     years: 6,
+    ///     months: 6,
+    ///     days: 10,
+    ///     hours: 0,
+    ///     minutes: 12,
+    ///     seconds: 37,
+    ///     is_future: true,
+    /// };
+    /// assert_eq!(
+    ///     diff.long_form_words(),
+    ///     "شش سال و شش ماه و ده روز و دوازده دقیقه و سی و هفت ثانیه بعد"
+    /// );
+    /// ```
+    #[cfg(feature = "number-to-words")]
+    pub fn long_form_words(&self) -> String {
+        TimeDiffFormatter::new().spell_numbers(true).format(self)
+    }
+
+    /// Returns the difference as a signed [Duration], negative for past\
+    /// A month is considered 30 days and a year 365 days.\
+    /// Differences longer than [Duration::MAX] are saturated.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rust_persian_tools::time_diff::TimeDiff;
+    /// use chrono::Duration;
+    ///
+    /// let diff = TimeDiff {
+    ///     years: 0,
+    ///     months: 0,
+    ///     days: 1,
+    ///     hours: 2,
+    ///     minutes: 0,
+    ///     seconds: 0,
+    ///     is_future: false,
+    /// };
+    /// assert_eq!(diff.to_duration(), Duration::try_hours(-26).unwrap());
+    /// ```
+    pub fn to_duration(&self) -> Duration {
+        let seconds = self.years as i64 * YEAR
+            + self.months as i64 * MONTH
+            + self.days as i64 * DAY
+            + self.hours as i64 * HOUR
+            + self.minutes as i64 * MINUTE
+            + self.seconds as i64;
+
+        // saturate for differences that are too large (hundreds of millions of years)
+        let duration = Duration::try_seconds(seconds).unwrap_or(Duration::MAX);
+
+        if self.is_future {
+            duration
+        } else {
+            -duration
+        }
+    }
+
     pub fn pre_or_next(&self) -> String {
         if self.is_future {
             "بعد".to_owned()
@@ -155,6 +357,13 @@ impl TimeDiff {
 /// - `%Y-%m-%dT%H:%M:%S%.3f%:z`: ISO 8601 with milliseconds and timezone offset
 /// - `%a, %d %b %Y %H:%M:%S %z`: RFC 2822 Format
 ///
+/// Jalali datetime strings are also supported in these formats (with Persian, Arabic or English digits):
+///
+/// - `%Y/%m/%d %H:%M:%S`, `%Y/%m/%d %H:%M` and `%Y/%m/%d`
+/// - `%Y-%m-%d %H:%M:%S`, `%Y-%m-%d %H:%M` and `%Y-%m-%d`
+///
+/// Calendar is detected by [Calendar::detect]
+///
 ///
 ///  timezone is set with the current timezone of the OS.
 ///
@@ -165,6 +374,10 @@ impl TimeDiff {
 ///
 /// assert!(convert_to_timestamp("2023/12/30 12:21:13").is_ok());
 /// assert!(convert_to_timestamp("2023/12/30 25:21:13").is_err());
+/// assert_eq!(
+///     convert_to_timestamp("۱۴۰۲/۱۰/۰۹ ۱۲:۲۱:۱۳"),
+///     convert_to_timestamp("2023/12/30 12:21:13")
+/// );
 /// ```
 pub fn convert_to_timestamp(datetime: impl AsRef<str>) -> Result<i64, TimeAgoError> {
     let datetime = datetime.as_ref();
@@ -173,6 +386,29 @@ pub fn convert_to_timestamp(datetime: impl AsRef<str>) -> Result<i64, TimeAgoErr
     Ok(date_obj.timestamp())
 }
 
+/// Converts a valid datetime of the given [Calendar] to timestamp
+///
+/// See [convert_to_timestamp] for supported formats.
+///
+/// # Examples
+///
+/// ```
+/// use rust_persian_tools::time_diff::{convert_to_timestamp_with_calendar, Calendar};
+///
+/// assert_eq!(
+///     convert_to_timestamp_with_calendar("1402/10/09 12:21:13", Calendar::Jalali),
+///     convert_to_timestamp_with_calendar("2023/12/30 12:21:13", Calendar::Gregorian)
+/// );
+/// ```
+pub fn convert_to_timestamp_with_calendar(
+    datetime: impl AsRef<str>,
+    calendar: Calendar,
+) -> Result<i64, TimeAgoError> {
+    let date_obj = get_date_time_with_calendar(datetime, calendar)?;
+
+    Ok(date_obj.timestamp())
+}
+
 /// Converts datetime to Chrono `DateTime<Local>`
 ///
 /// # Warning
@@ -184,6 +420,13 @@ pub fn convert_to_timestamp(datetime: impl AsRef<str>) -> Result<i64, TimeAgoErr
 /// - `%Y-%m-%dT%H:%M:%S%.3f%:z`: ISO 8601 with milliseconds and timezone offset
 /// - `%a, %d %b %Y %H:%M:%S %z`: RFC 2822 Format
 ///
+/// Jalali datetime strings are also supported in these formats (with Persian, Arabic or English digits):
+///
+/// - `%Y/%m/%d %H:%M:%S`, `%Y/%m/%d %H:%M` and `%Y/%m/%d`
+/// - `%Y-%m-%d %H:%M:%S`, `%Y-%m-%d %H:%M` and `%Y-%m-%d`
+///
+/// Calendar is detected by [Calendar::detect]
+///
 ///  timezone is set with the current timezone of the OS.
 ///
 /// # Examples
@@ -193,30 +436,182 @@ pub fn convert_to_timestamp(datetime: impl AsRef<str>) -> Result<i64, TimeAgoErr
 ///
 /// assert!(get_date_time("2019/03/18 12:22:14").is_ok());
 /// assert!(get_date_time("20192/03/18 12:22:14").is_err());
+/// assert!(get_date_time("۱۳۹۷/۱۲/۲۷ ۱۲:۲۲").is_ok());
 /// ```
 pub fn get_date_time(datetime: impl AsRef<str>) -> Result<DateTime<Local>, TimeAgoError> {
     let datetime = datetime.as_ref();
 
+    get_date_time_with_calendar(datetime, Calendar::detect(datetime))
+}
+
+/// Converts datetime of the given [Calendar] to Chrono `DateTime<Local>`
+///
+/// See [get_date_time] for supported formats.
+///
+///  timezone is set with the current timezone of the OS.
+///
+/// # Examples
+///
+/// ```
+/// use rust_persian_tools::time_diff::{get_date_time_with_calendar, Calendar};
+///
+/// assert_eq!(
+///     get_date_time_with_calendar("1397/12/27 12:22:14", Calendar::Jalali),
+///     get_date_time_with_calendar("2019/03/18 12:22:14", Calendar::Gregorian)
+/// );
+/// assert!(get_date_time_with_calendar("1397/12/30 12:22:14", Calendar::Jalali).is_err());
+/// ```
+pub fn get_date_time_with_calendar(
+    datetime: impl AsRef<str>,
+    calendar: Calendar,
+) -> Result<DateTime<Local>, TimeAgoError> {
+    // offset of the string is ignored here to keep the previous behavior
+    let (parsed, _) = parse_date_time(datetime.as_ref(), calendar)?;
+
+    match Local.from_local_datetime(&parsed).earliest() {
+        Some(local_date_time) => Ok(local_date_time),
+        None => Err(TimeAgoError::Unknown),
+    }
+}
+
+/// Converts datetime to Chrono `DateTime<Tz>` in the given timezone
+///
+/// Unlike [get_date_time], the result does not depend on timezone of the OS:
+/// - Datetime strings without offset are considered to be in `tz`
+/// - Datetime strings with offset (ISO 8601 and RFC 2822) are converted to `tz`
+///
+/// See [get_date_time] for supported formats. Calendar is detected by [Calendar::detect]
+///
+/// # Examples
+///
+/// ```
+/// use rust_persian_tools::time_diff::{get_date_time_in, tehran_offset};
+/// use chrono::{TimeZone, Utc};
+///
+/// let tehran = tehran_offset();
+/// assert_eq!(
+///     get_date_time_in("۱۴۰۳/۰۷/۲۶ ۱۵:۳۰", &tehran),
+///     Ok(tehran.with_ymd_and_hms(2024, 10, 17, 15, 30, 0).unwrap())
+/// );
+/// assert_eq!(
+///     get_date_time_in("2024-10-17T12:00:00+00:00", &tehran),
+///     Ok(tehran.with_ymd_and_hms(2024, 10, 17, 15, 30, 0).unwrap())
+/// );
+/// assert_eq!(
+///     get_date_time_in("2024/10/17 15:30:00", &Utc),
+///     Ok(Utc.with_ymd_and_hms(2024, 10, 17, 15, 30, 0).unwrap())
+/// );
+/// ```
+pub fn get_date_time_in<Tz: TimeZone>(
+    datetime: impl AsRef<str>,
+    tz: &Tz,
+) -> Result<DateTime<Tz>, TimeAgoError> {
+    let datetime = datetime.as_ref();
+    let (parsed, offset) = parse_date_time(datetime, Calendar::detect(datetime))?;
+
+    let date_time = match offset {
+        Some(offset) => offset
+            .from_local_datetime(&parsed)
+            .single()
+            .map(|date_time| date_time.with_timezone(tz)),
+        None => tz.from_local_datetime(&parsed).earliest(),
+    };
+
+    date_time.ok_or(TimeAgoError::Unknown)
+}
+
+/// Converts a valid datetime in the given timezone to timestamp
+///
+/// See [get_date_time_in] for how timezone is applied.
+///
+/// # Examples
+///
+/// ```
+/// use rust_persian_tools::time_diff::{convert_to_timestamp_in, tehran_offset};
+/// use chrono::Utc;
+///
+/// assert_eq!(
+///     convert_to_timestamp_in("2024/10/17 15:30:00", &tehran_offset()),
+///     convert_to_timestamp_in("2024/10/17 12:00:00", &Utc)
+/// );
+/// ```
+pub fn convert_to_timestamp_in<Tz: TimeZone>(
+    datetime: impl AsRef<str>,
+    tz: &Tz,
+) -> Result<i64, TimeAgoError> {
+    Ok(get_date_time_in(datetime, tz)?.timestamp())
+}
+
+/// Returns offset of Asia/Tehran timezone (+03:30)
+///
+/// Iran does not observe daylight saving time since 2023, until then the offset was +04:30 in summers
+/// (about 22 March to 22 September). So this offset and the `*_tehran` functions are an hour off for
+/// summer datetimes of 2022 and earlier, use the `*_in` functions with a timezone that has the history
+/// of Asia/Tehran (e.g. from `chrono-tz`) for them.
+pub fn tehran_offset() -> FixedOffset {
+    // this is safe because offset is less than a day
+    FixedOffset::east_opt((3 * HOUR + 30 * MINUTE) as i32).expect("valid offset")
+}
+
+/// Same as [get_date_time_in] in Asia/Tehran timezone, see [tehran_offset] for datetimes before 2023
+pub fn get_date_time_tehran(
+    datetime: impl AsRef<str>,
+) -> Result<DateTime<FixedOffset>, TimeAgoError> {
+    get_date_time_in(datetime, &tehran_offset())
+}
+
+/// Same as [convert_to_timestamp_in] in Asia/Tehran timezone, see [tehran_offset] for datetimes before 2023
+///
+/// # Examples
+///
+/// ```
+/// use rust_persian_tools::time_diff::convert_to_timestamp_tehran;
+///
+/// assert_eq!(convert_to_timestamp_tehran("۱۴۰۳/۰۷/۲۶ ۱۵:۳۰"), Ok(1729166400));
+/// ```
+pub fn convert_to_timestamp_tehran(datetime: impl AsRef<str>) -> Result<i64, TimeAgoError> {
+    convert_to_timestamp_in(datetime, &tehran_offset())
+}
+
+/// Returns the naive datetime and its offset if the string has one
+fn parse_date_time(
+    datetime: &str,
+    calendar: Calendar,
+) -> Result<(NaiveDateTime, Option<FixedOffset>), TimeAgoError> {
+    match calendar {
+        Calendar::Gregorian => parse_gregorian(datetime),
+        Calendar::Jalali => datetime
+            .parse::<JalaliDateTime>()
+            .map(|parsed| (parsed.to_gregorian(), None))
+            .map_err(|_| TimeAgoError::InvalidDateTimeFormat),
+    }
+}
+
+fn parse_gregorian(datetime: &str) -> Result<(NaiveDateTime, Option<FixedOffset>), TimeAgoError> {
+    let datetime = datetime.digits_fa_to_en().digits_ar_to_en();
+
     let formats = [
-        "%Y-%m-%d %H:%M:%S",        // Sortable format
-        "%Y/%m/%d %H:%M:%S",        // Sortable format
+        "%Y-%m-%d %H:%M:%S", // Sortable format
+        "%Y/%m/%d %H:%M:%S", // Sortable format
+    ];
+    let formats_with_offset = [
         "%Y-%m-%dT%H:%M:%S%:z",     // ISO 8601 with timezone offset
         "%Y-%m-%dT%H:%M:%S%.3f%:z", // ISO 8601 with milliseconds and timezone offset
         "%a, %d %b %Y %H:%M:%S %z", // RFC 2822 Format
     ];
 
-    for format in formats {
-        if let Ok(parsed) = NaiveDateTime::parse_from_str(datetime, format) {
-            // Successfully parsed, convert to timestamp
-            let datetime_with_timezone = Local.from_local_datetime(&parsed).earliest();
-            return match datetime_with_timezone {
-                Some(local_date_time) => Ok(local_date_time),
-                None => Err(TimeAgoError::Unknown),
-            };
-        }
-    }
-
-    Err(TimeAgoError::InvalidDateTimeFormat)
+    formats
+        .iter()
+        .find_map(|format| NaiveDateTime::parse_from_str(&datetime, format).ok())
+        .map(|parsed| (parsed, None))
+        .or_else(|| {
+            formats_with_offset.iter().find_map(|format| {
+                DateTime::parse_from_str(&datetime, format)
+                    .ok()
+                    .map(|parsed| (parsed.naive_local(), Some(*parsed.offset())))
+            })
+        })
+        .ok_or(TimeAgoError::InvalidDateTimeFormat)
 }
 
 /// Returns current timestamp
@@ -252,7 +647,15 @@ pub fn get_current_timestamp() -> i64 {
 /// - `%Y-%m-%dT%H:%M:%S%.3f%:z`: ISO 8601 with milliseconds and timezone offset
 /// - `%a, %d %b %Y %H:%M:%S %z`: RFC 2822 Format
 ///
+/// Jalali datetime strings are also supported in these formats (with Persian, Arabic or English digits):
+///
+/// - `%Y/%m/%d %H:%M:%S`, `%Y/%m/%d %H:%M` and `%Y/%m/%d`
+/// - `%Y-%m-%d %H:%M:%S`, `%Y-%m-%d %H:%M` and `%Y-%m-%d`
+///
+/// Calendar is detected by [Calendar::detect]
+///
 ///  timezone is set with the current timezone of the OS.
+///  Use [time_diff_now_tehran] or [time_diff_now_in] for a result that does not depend on it.
 ///
 /// # Examples
 ///
@@ -288,10 +691,35 @@ pub fn get_current_timestamp() -> i64 {
 /// ```
 pub fn time_diff_now(datetime: impl Into<Timestamp>) -> Result<TimeDiff, TimeAgoError> {
     let ts_now = get_current_timestamp();
-    let ts = match datetime.into() {
-        Timestamp::String(datetime_str) => convert_to_timestamp(datetime_str)?,
-        Timestamp::Integer(timestamp) => timestamp,
-    };
+    let ts = to_timestamp(datetime.into(), None)?;
+
+    let timestamp_diff = ts - ts_now;
+
+    Ok(get_time_diff(timestamp_diff))
+}
+
+/// Same as [time_diff_now] but datetime string is parsed in the given [Calendar] instead of detecting it
+///
+/// # Examples
+///
+/// ```
+/// use rust_persian_tools::time_diff::{time_diff_now_with_calendar, Calendar};
+/// use rust_persian_tools::jalali::JalaliDateTime;
+/// use chrono::{Duration, Local};
+///
+/// let ten_minutes_ago = Local::now().naive_local() - Duration::try_minutes(10).unwrap();
+/// let formatted_time = JalaliDateTime::from_gregorian(ten_minutes_ago)
+///     .unwrap()
+///     .to_string();
+/// assert!(time_diff_now_with_calendar(formatted_time, Calendar::Jalali)
+///     .is_ok_and(|datetime| datetime.short_form() == "10 دقیقه قبل"));
+/// ```
+pub fn time_diff_now_with_calendar(
+    datetime: impl Into<Timestamp>,
+    calendar: Calendar,
+) -> Result<TimeDiff, TimeAgoError> {
+    let ts_now = get_current_timestamp();
+    let ts = to_timestamp(datetime.into(), Some(calendar))?;
 
     let timestamp_diff = ts - ts_now;
 
@@ -319,7 +747,15 @@ pub fn time_diff_now(datetime: impl Into<Timestamp>) -> Result<TimeDiff, TimeAgo
 /// - `%Y-%m-%dT%H:%M:%S%.3f%:z`: ISO 8601 with milliseconds and timezone offset
 /// - `%a, %d %b %Y %H:%M:%S %z`: RFC 2822 Format
 ///
+/// Jalali datetime strings are also supported in these formats (with Persian, Arabic or English digits):
+///
+/// - `%Y/%m/%d %H:%M:%S`, `%Y/%m/%d %H:%M` and `%Y/%m/%d`
+/// - `%Y-%m-%d %H:%M:%S`, `%Y-%m-%d %H:%M` and `%Y-%m-%d`
+///
+/// Calendar is detected by [Calendar::detect]
+///
 ///  timezone is set with the current timezone of the OS.
+///  Use [time_diff_between_tehran] or [time_diff_between_in] for a result that does not depend on it.
 ///
 /// # Examples
 ///
@@ -370,21 +806,177 @@ pub fn time_diff_between(
     start: impl Into<Timestamp>,
     end: impl Into<Timestamp>,
 ) -> Result<TimeDiff, TimeAgoError> {
-    let ts_start = match start.into() {
-        Timestamp::String(datetime_str) => convert_to_timestamp(datetime_str)?,
-        Timestamp::Integer(timestamp) => timestamp,
-    };
+    let ts_start = to_timestamp(start.into(), None)?;
+    let ts_end = to_timestamp(end.into(), None)?;
 
-    let ts_end = match end.into() {
-        Timestamp::String(datetime_str) => convert_to_timestamp(datetime_str)?,
-        Timestamp::Integer(timestamp) => timestamp,
-    };
+    let timestamp_diff = ts_end - ts_start;
+
+    Ok(get_time_diff(timestamp_diff))
+}
+
+/// Same as [time_diff_between] but datetime strings are parsed in the given [Calendar] instead of detecting it
+///
+/// # Examples
+///
+/// ```
+/// use rust_persian_tools::time_diff::{time_diff_between_with_calendar, Calendar};
+///
+/// let diff = time_diff_between_with_calendar(
+///     "1403/01/01 00:00:00",
+///     "1403/01/02 12:30:00",
+///     Calendar::Jalali,
+/// )
+/// .unwrap();
+/// assert_eq!(diff.long_form(), "1 روز و 12 ساعت و 30 دقیقه بعد");
+/// ```
+pub fn time_diff_between_with_calendar(
+    start: impl Into<Timestamp>,
+    end: impl Into<Timestamp>,
+    calendar: Calendar,
+) -> Result<TimeDiff, TimeAgoError> {
+    let ts_start = to_timestamp(start.into(), Some(calendar))?;
+    let ts_end = to_timestamp(end.into(), Some(calendar))?;
 
     let timestamp_diff = ts_end - ts_start;
 
     Ok(get_time_diff(timestamp_diff))
 }
 
+/// Same as [time_diff_now] but datetime strings without offset are considered to be in the given timezone
+/// instead of timezone of the OS
+///
+/// # Examples
+///
+/// ```
+/// use rust_persian_tools::time_diff::{time_diff_now_in, tehran_offset};
+/// use chrono::{Duration, Utc};
+///
+/// let tehran = tehran_offset();
+/// let ten_minutes_ago = Utc::now().with_timezone(&tehran) - Duration::try_minutes(10).unwrap();
+/// let formatted_time = ten_minutes_ago.format("%Y-%m-%d %H:%M:%S").to_string();
+/// assert!(time_diff_now_in(formatted_time, &tehran)
+///     .is_ok_and(|datetime| datetime.short_form() == "10 دقیقه قبل"));
+/// ```
+pub fn time_diff_now_in<Tz: TimeZone>(
+    datetime: impl Into<Timestamp>,
+    tz: &Tz,
+) -> Result<TimeDiff, TimeAgoError> {
+    time_diff_now_at(datetime, &Utc::now().with_timezone(tz))
+}
+
+/// Same as [time_diff_now] but current time is given by `now`\
+/// Datetime strings without offset are considered to be in timezone of `now`
+///
+/// This makes the result deterministic, e.g. in tests.
+///
+/// # Examples
+///
+/// ```
+/// use rust_persian_tools::time_diff::{time_diff_now_at, tehran_offset};
+/// use chrono::TimeZone;
+///
+/// let now = tehran_offset().with_ymd_and_hms(2024, 10, 17, 12, 0, 0).unwrap();
+/// assert_eq!(
+///     time_diff_now_at("۱۴۰۳/۰۷/۲۶ ۱۱:۵۰", &now).unwrap().short_form(),
+///     "10 دقیقه قبل"
+/// );
+/// assert_eq!(
+///     time_diff_now_at("2024-10-17T12:00:00+00:00", &now).unwrap().long_form(),
+///     "3 ساعت و 30 دقیقه بعد"
+/// );
+/// ```
+pub fn time_diff_now_at<Tz: TimeZone>(
+    datetime: impl Into<Timestamp>,
+    now: &DateTime<Tz>,
+) -> Result<TimeDiff, TimeAgoError> {
+    let ts = to_timestamp_in(datetime.into(), &now.timezone())?;
+
+    let timestamp_diff = ts - now.timestamp();
+
+    Ok(get_time_diff(timestamp_diff))
+}
+
+/// Same as [time_diff_between] but datetime strings without offset are considered to be in the given timezone
+/// instead of timezone of the OS
+///
+/// # Examples
+///
+/// ```
+/// use rust_persian_tools::time_diff::{time_diff_between_in, tehran_offset};
+///
+/// let diff = time_diff_between_in(
+///     "2024-10-17T12:00:00+00:00",
+///     "2024/10/17 16:30:00",
+///     &tehran_offset(),
+/// )
+/// .unwrap();
+/// assert_eq!(diff.long_form(), "1 ساعت بعد");
+/// ```
+pub fn time_diff_between_in<Tz: TimeZone>(
+    start: impl Into<Timestamp>,
+    end: impl Into<Timestamp>,
+    tz: &Tz,
+) -> Result<TimeDiff, TimeAgoError> {
+    let ts_start = to_timestamp_in(start.into(), tz)?;
+    let ts_end = to_timestamp_in(end.into(), tz)?;
+
+    let timestamp_diff = ts_end - ts_start;
+
+    Ok(get_time_diff(timestamp_diff))
+}
+
+/// Same as [time_diff_now_in] in Asia/Tehran timezone, see [tehran_offset] for datetimes before 2023
+///
+/// # Examples
+///
+/// ```
+/// use rust_persian_tools::time_diff::{tehran_offset, time_diff_now_tehran};
+/// use chrono::{Duration, Utc};
+///
+/// let ten_minutes_ago = Utc::now().with_timezone(&tehran_offset()) - Duration::try_minutes(10).unwrap();
+/// let formatted_time = ten_minutes_ago.format("%Y-%m-%d %H:%M:%S").to_string();
+/// assert!(time_diff_now_tehran(formatted_time)
+///     .is_ok_and(|datetime| datetime.short_form() == "10 دقیقه قبل"));
+/// ```
+pub fn time_diff_now_tehran(datetime: impl Into<Timestamp>) -> Result<TimeDiff, TimeAgoError> {
+    time_diff_now_in(datetime, &tehran_offset())
+}
+
+/// Same as [time_diff_between_in] in Asia/Tehran timezone, see [tehran_offset] for datetimes before 2023
+///
+/// # Examples
+///
+/// ```
+/// use rust_persian_tools::time_diff::time_diff_between_tehran;
+///
+/// let diff = time_diff_between_tehran("2024-10-17T12:00:00+00:00", "۱۴۰۳/۰۷/۲۶ ۱۶:۳۰").unwrap();
+/// assert_eq!(diff.long_form(), "1 ساعت بعد");
+/// ```
+pub fn time_diff_between_tehran(
+    start: impl Into<Timestamp>,
+    end: impl Into<Timestamp>,
+) -> Result<TimeDiff, TimeAgoError> {
+    time_diff_between_in(start, end, &tehran_offset())
+}
+
+fn to_timestamp_in<Tz: TimeZone>(datetime: Timestamp, tz: &Tz) -> Result<i64, TimeAgoError> {
+    match datetime {
+        Timestamp::String(datetime_str) => convert_to_timestamp_in(datetime_str, tz),
+        Timestamp::Integer(timestamp) => Ok(timestamp),
+    }
+}
+
+/// Calendar is detected if not given
+fn to_timestamp(datetime: Timestamp, calendar: Option<Calendar>) -> Result<i64, TimeAgoError> {
+    match (datetime, calendar) {
+        (Timestamp::String(datetime_str), Some(calendar)) => {
+            convert_to_timestamp_with_calendar(datetime_str, calendar)
+        }
+        (Timestamp::String(datetime_str), None) => convert_to_timestamp(datetime_str),
+        (Timestamp::Integer(timestamp), _) => Ok(timestamp),
+    }
+}
+
 fn get_time_diff(timestamp_diff: i64) -> TimeDiff {
     let is_future = timestamp_diff > 0;
 
@@ -564,4 +1156,174 @@ mod tests {
         assert!(get_date_time("2019/03/18 12:22:14").is_ok());
         assert!(get_date_time("20192/03/18 12:22:14").is_err());
     }
+
+    #[test]
+    fn test_jalali_date_time() {
+        assert_eq!(
+            get_date_time("۱۳۹۷/۱۲/۲۷ ۱۲:۲۲:۱۴"),
+            get_date_time("2019/03/18 12:22:14")
+        );
+        assert_eq!(
+            get_date_time("1397-12-27 12:22"),
+            get_date_time("2019-03-18 12:22:00")
+        );
+        assert_eq!(
+            get_date_time("۱۳۹۷/۱۲/۳۰ ۱۲:۲۲:۱۴"),
+            Err(TimeAgoError::InvalidDateTimeFormat)
+        );
+        assert_eq!(
+            get_date_time_with_calendar("1403/01/01 00:00:00", Calendar::Gregorian)
+                .unwrap()
+                .naive_local(),
+            NaiveDateTime::parse_from_str("1403/01/01 00:00:00", "%Y/%m/%d %H:%M:%S").unwrap()
+        );
+    }
+
+    #[test]
+    fn test_time_diff_calendar_between() {
+        let start = Local.with_ymd_and_hms(2023, 1, 31, 10, 0, 0).unwrap();
+        let end = Local.with_ymd_and_hms(2023, 3, 1, 9, 0, 0).unwrap();
+        assert_eq!(
+            TimeDiff::calendar_between(&start, &end, Calendar::Gregorian),
+            Some(TimeDiff {
+                years: 0,
+                months: 1,
+                days: 0,
+                hours: 23,
+                minutes: 0,
+                seconds: 0,
+                is_future: true,
+            })
+        );
+
+        // leap day is counted
+        let start = Local.with_ymd_and_hms(2024, 2, 1, 0, 0, 0).unwrap();
+        let end = Local.with_ymd_and_hms(2024, 3, 1, 0, 0, 0).unwrap();
+        assert_eq!(
+            TimeDiff::calendar_between(&start, &end, Calendar::Gregorian)
+                .unwrap()
+                .long_form(),
+            "1 ماه بعد"
+        );
+        assert_eq!(
+            TimeDiff::calendar_between(&start, &end, Calendar::Jalali)
+                .unwrap()
+                .long_form(),
+            "29 روز بعد"
+        );
+
+        // 1402/12/29 -> 1403/12/30 (leap year in Jalali calendar)
+        let start = Local.with_ymd_and_hms(2024, 3, 19, 0, 0, 0).unwrap();
+        let end = Local.with_ymd_and_hms(2025, 3, 20, 0, 0, 0).unwrap();
+        assert_eq!(
+            TimeDiff::calendar_between(&end, &start, Calendar::Jalali)
+                .unwrap()
+                .long_form(),
+            "1 سال و 1 روز قبل"
+        );
+        assert_eq!(
+            TimeDiff::calendar_between(&start, &start, Calendar::Jalali)
+                .unwrap()
+                .short_form(),
+            "اکنون"
+        );
+
+        // 30 days are not a month in a 31-day month
+        let start = Local.with_ymd_and_hms(2023, 1, 1, 0, 0, 0).unwrap();
+        let end = Local.with_ymd_and_hms(2023, 1, 31, 0, 0, 0).unwrap();
+        assert_eq!(
+            TimeDiff::calendar_between(&start, &end, Calendar::Gregorian)
+                .unwrap()
+                .long_form(),
+            "30 روز بعد"
+        );
+
+        // out of the supported range of Jalali calendar
+        let start = Utc.with_ymd_and_hms(9000, 1, 1, 0, 0, 0).unwrap();
+        let end = start + Duration::days(300);
+        assert_eq!(
+            TimeDiff::calendar_between(&start, &end, Calendar::Jalali),
+            None
+        );
+        assert_eq!(
+            TimeDiff::calendar_between(&start, &end, Calendar::Gregorian)
+                .unwrap()
+                .long_form(),
+            "9 ماه و 27 روز بعد"
+        );
+    }
+
+    #[test]
+    fn test_time_diff_with_timezone() {
+        let tehran = tehran_offset();
+        let now = tehran.with_ymd_and_hms(2024, 10, 17, 12, 0, 0).unwrap();
+
+        assert_eq!(
+            time_diff_now_at("2024/10/17 11:50:00", &now).unwrap(),
+            TimeDiff {
+                years: 0,
+                months: 0,
+                days: 0,
+                hours: 0,
+                minutes: 10,
+                seconds: 0,
+                is_future: false,
+            }
+        );
+        assert_eq!(
+            time_diff_now_at("Thu, 17 Oct 2024 12:00:00 +0000", &now)
+                .unwrap()
+                .short_form(),
+            "3 ساعت بعد"
+        );
+        assert_eq!(
+            time_diff_now_at(now.timestamp() + 2 * DAY, &now)
+                .unwrap()
+                .short_form(),
+            "حدود 2 روز بعد"
+        );
+        assert_eq!(
+            time_diff_now_at("2024/10/17 08:30:00", &now.with_timezone(&Utc))
+                .unwrap()
+                .short_form(),
+            "اکنون"
+        );
+        assert_eq!(
+            time_diff_between_in("2024/10/17 12:00:00", "2024/10/17 12:00:00", &Utc),
+            time_diff_between_in("2024/10/17 12:00:00", "2024/10/17 12:00:00", &tehran)
+        );
+        assert_eq!(
+            time_diff_now_at("2024/13/17 12:00:00", &now),
+            Err(TimeAgoError::InvalidDateTimeFormat)
+        );
+
+        assert_eq!(get_date_time_tehran("2024/10/17 12:00:00"), Ok(now));
+        assert_eq!(
+            convert_to_timestamp_tehran("2024/10/17 12:00:00"),
+            Ok(now.timestamp())
+        );
+        assert_eq!(
+            time_diff_between_tehran("2024/10/17 08:30:00", now.timestamp()),
+            time_diff_between_in("2024/10/17 08:30:00", "2024/10/17 12:00:00", &Utc)
+        );
+    }
+
+    #[test]
+    fn test_time_diff_jalali() {
+        let current_time = Local::now().naive_local();
+        let three_days_later = current_time + Duration::try_days(3).unwrap();
+        let formatted_time = JalaliDateTime::from_gregorian(three_days_later)
+            .unwrap()
+            .format_with_digits("%Y/%m/%d %H:%M:%S", crate::digits::Language::Persian);
+
+        assert!(time_diff_now(formatted_time)
+            .is_ok_and(|datetime| datetime.short_form() == "حدود 3 روز بعد"));
+
+        assert_eq!(
+            time_diff_between("1402/12/29 23:00:00", "2024/03/20 01:00:00")
+                .unwrap()
+                .long_form(),
+            "2 ساعت بعد"
+        );
+    }
 }
@@ -0,0 +1,257 @@
+use chrono::Duration;
+use thiserror::Error;
+
+use super::{get_time_diff, TimeDiff, DAY, HOUR, MINUTE, MONTH, YEAR};
+use crate::digits::{DigitsAr2En, DigitsFa2En};
+use crate::words_to_number::{words_to_number, WordsToNumberError};
+
+const WEEK: i64 = DAY * 7;
+
+/// Error of [parse_relative] and [parse_relative_duration]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Error, Clone, Debug, Hash, PartialEq, Eq)]
+pub enum RelativeTimeError {
+    #[error("Wrong relative time phrase !")]
+    InvalidPhrase,
+    /// A quantity written in words is invalid\
+    /// Spans of the source error are byte ranges in the quantity words joined by single spaces
+    #[error("Wrong quantity in relative time phrase: {0}")]
+    InvalidQuantity(#[from] WordsToNumberError),
+}
+
+const NOW_PHRASES: [&str; 4] = ["اکنون", "الان", "همین الان", "هم اکنون"];
+const APPROXIMATE_PREFIXES: [&str; 3] = ["حدود", "حدودا", "تقریبا"];
+
+fn unit_seconds(word: &str) -> Option<i64> {
+    Some(match word {
+        "ثانیه" => 1,
+        "دقیقه" => MINUTE,
+        "ساعت" => HOUR,
+        "روز" => DAY,
+        "هفته" => WEEK,
+        "ماه" => MONTH,
+        "سال" => YEAR,
+        _ => return None,
+    })
+}
+
+/// Parses a Persian relative time phrase into a [TimeDiff]\
+/// This is the inverse of [TimeDiff::short_form] and [TimeDiff::long_form]
+///
+/// - Quantities can be written with Persian, Arabic or English digits or as Persian words
+/// - "قبل" and "پیش" are used for past, "بعد" and "دیگر" for future
+/// - "حدود" prefix is accepted and ignored
+/// - "اکنون", "الان" and "همین الان" mean now
+///
+/// Like the rest of this module, a month is 30 days and a year is 365 days,
+/// so the result is normalized the same way as [time_diff_between](super::time_diff_between).
+///
+/// # Examples
+///
+/// ```
+/// use rust_persian_tools::time_diff::{parse_relative, TimeDiff};
+///
+/// assert_eq!(
+///     parse_relative("یک ساعت و بیست دقیقه دیگر"),
+///     Ok(TimeDiff {
+///         years: 0,
+///         months: 0,
+///         days: 0,
+///         hours: 1,
+///         minutes: 20,
+///         seconds: 0,
+///         is_future: true,
+///     })
+/// );
+/// assert_eq!(parse_relative("حدود ۲ هفته بعد").unwrap().days, 14);
+/// assert_eq!(parse_relative("۳ روز پیش").unwrap().short_form(), "حدود 3 روز قبل");
+/// assert!(parse_relative("دیروز").is_err());
+/// ```
+pub fn parse_relative(phrase: impl AsRef<str>) -> Result<TimeDiff, RelativeTimeError> {
+    let seconds = parse_relative_seconds(phrase.as_ref())?;
+    if seconds.abs() / YEAR > u32::MAX as i64 {
+        return Err(RelativeTimeError::InvalidPhrase);
+    }
+
+    Ok(get_time_diff(seconds))
+}
+
+/// Same as [parse_relative] but returns a signed [Duration], negative for past
+///
+/// # Examples
+///
+/// ```
+/// use rust_persian_tools::time_diff::parse_relative_duration;
+/// use chrono::Duration;
+///
+/// assert_eq!(
+///     parse_relative_duration("بیست و پنج دقیقه پیش"),
+///     Ok(Duration::try_minutes(-25).unwrap())
+/// );
+/// assert_eq!(
+///     parse_relative_duration("1 روز و 2 ساعت دیگر"),
+///     Ok(Duration::try_hours(26).unwrap())
+/// );
+/// ```
+pub fn parse_relative_duration(phrase: impl AsRef<str>) -> Result<Duration, RelativeTimeError> {
+    let seconds = parse_relative_seconds(phrase.as_ref())?;
+
+    Duration::try_seconds(seconds).ok_or(RelativeTimeError::InvalidPhrase)
+}
+
+fn parse_relative_seconds(phrase: &str) -> Result<i64, RelativeTimeError> {
+    let phrase = phrase
+        .digits_fa_to_en()
+        .digits_ar_to_en()
+        .replace('ي', "ی")
+        .replace('ك', "ک")
+        .replace('\u{200c}', " ");
+    let phrase = phrase.split_whitespace().collect::<Vec<_>>().join(" ");
+
+    if NOW_PHRASES.contains(&phrase.as_str()) {
+        return Ok(0);
+    }
+
+    let mut words = phrase.split(' ').collect::<Vec<_>>();
+    if words
+        .first()
+        .is_some_and(|word| APPROXIMATE_PREFIXES.contains(word))
+    {
+        words.remove(0);
+    }
+
+    let sign = match words.pop() {
+        Some("قبل" | "پیش") => -1,
+        Some("بعد" | "دیگر") => 1,
+        _ => return Err(RelativeTimeError::InvalidPhrase),
+    };
+
+    let mut seen_units = Vec::new();
+    let mut quantity = Vec::new();
+    let mut total: i64 = 0;
+
+    for word in words {
+        let Some(unit) = unit_seconds(word) else {
+            // "و" between two periods
+            if !(quantity.is_empty() && word == "و") {
+                quantity.push(word);
+            }
+            continue;
+        };
+        if seen_units.contains(&unit) {
+            return Err(RelativeTimeError::InvalidPhrase);
+        }
+        seen_units.push(unit);
+
+        let value = parse_quantity(&quantity)?;
+        quantity.clear();
+
+        total = value
+            .checked_mul(unit)
+            .and_then(|seconds| total.checked_add(seconds))
+            .ok_or(RelativeTimeError::InvalidPhrase)?;
+    }
+
+    // a quantity without unit is left or there was no period at all
+    if !quantity.is_empty() || seen_units.is_empty() {
+        return Err(RelativeTimeError::InvalidPhrase);
+    }
+
+    Ok(sign * total)
+}
+
+fn parse_quantity(words: &[&str]) -> Result<i64, RelativeTimeError> {
+    let value = match words {
+        [] => return Err(RelativeTimeError::InvalidPhrase),
+        [number] if number.bytes().all(|b| b.is_ascii_digit()) => number
+            .parse::<i64>()
+            .map_err(|_| RelativeTimeError::InvalidPhrase)?,
+        _ => words_to_number(words.join(" "))?,
+    };
+
+    if value < 0 {
+        return Err(RelativeTimeError::InvalidPhrase);
+    }
+
+    Ok(value)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_relative_test() {
+        assert_eq!(parse_relative("اکنون").unwrap().short_form(), "اکنون");
+        assert_eq!(parse_relative(" همین‌الان ").unwrap().short_form(), "اکنون");
+        assert_eq!(
+            parse_relative("۱۰ دقیقه قبل").unwrap().short_form(),
+            "10 دقیقه قبل"
+        );
+        assert_eq!(
+            parse_relative("٣ ساعت و ٥ ثانیه بعد").unwrap().long_form(),
+            "3 ساعت و 5 ثانیه بعد"
+        );
+        assert_eq!(
+            parse_relative("حدود سی و یک روز پیش").unwrap().long_form(),
+            "1 ماه و 1 روز قبل"
+        );
+        assert_eq!(
+            parse_relative("دو سال و سه ماه دیگر").unwrap().long_form(),
+            "2 سال و 3 ماه بعد"
+        );
+
+        let diff = TimeDiff {
+            years: 6,
+            months: 6,
+            days: 10,
+            hours: 0,
+            minutes: 12,
+            seconds: 37,
+            is_future: true,
+        };
+        assert_eq!(parse_relative(diff.long_form()), Ok(diff));
+    }
+
+    #[test]
+    fn parse_relative_fail_test() {
+        for phrase in [
+            "",
+            "قبل",
+            "۳ روز",
+            "روز پیش",
+            "سه پیش",
+            "سه روز و دو پیش",
+            "یک روز و دو روز پیش",
+            "منفی یک روز پیش",
+            "99999999999999999 سال پیش",
+        ] {
+            assert_eq!(
+                parse_relative(phrase),
+                Err(RelativeTimeError::InvalidPhrase),
+                "{phrase}"
+            );
+        }
+
+        assert_eq!(
+            parse_relative("دو هزار سلام روز پیش"),
+            Err(RelativeTimeError::InvalidQuantity(
+                WordsToNumberError::InvalidUnit {
+                    token: "سلام".to_string(),
+                    span: 14..22,
+                }
+            ))
+        );
+    }
+
+    #[test]
+    fn to_duration_test() {
+        for phrase in ["۱ سال و ۲ ماه و ۳ روز پیش", "4 ساعت و 5 دقیقه بعد"]
+        {
+            assert_eq!(
+                parse_relative(phrase).unwrap().to_duration(),
+                parse_relative_duration(phrase).unwrap()
+            );
+        }
+    }
+}
@@ -0,0 +1,437 @@
+use super::constants::{get_magnitude_number, AND, NEGATIVE_PREFIX};
+use super::errors::WordsToNumberError;
+use super::parser::{parse, tokenize, without_ordinal_suffix, Token};
+use crate::digits::{DigitsAr2En, DigitsFa2En};
+
+/// Colloquial and Arabic spellings and their standard form
+fn standard_word(word: &str) -> Option<&'static str> {
+    Some(match word {
+        "یه" | "یکی" | "واحد" => "یک",
+        "اثنان" | "اثنین" | "اثنا" => "دو",
+        "ثلاث" | "ثلاثه" => "سه",
+        "چار" | "اربع" | "اربعه" => "چهار",
+        "خمس" | "خمسه" => "پنج",
+        "ست" | "سته" => "شش",
+        "هف" | "سبع" | "سبعه" => "هفت",
+        "هش" | "ثمان" | "ثمانی" | "ثمانیه" => "هشت",
+        "تسع" | "تسعه" => "نه",
+        "عشر" | "عشره" => "ده",
+        "چارده" => "چهارده",
+        "پونزده" => "پانزده",
+        "شونزده" => "شانزده",
+        "هیفده" | "هیوده" => "هفده",
+        "هیجده" | "هژده" => "هجده",
+        "عشرین" => "بیست",
+        "ثلاثین" => "سی",
+        "اربعین" => "چهل",
+        "پنجا" | "خمسین" => "پنجاه",
+        "ستین" => "شصت",
+        "سبعین" => "هفتاد",
+        "ثمانین" => "هشتاد",
+        "تسعین" => "نود",
+        "مائه" | "مئه" => "صد",
+        "دویس" | "دوییست" | "دوویست" => "دویست",
+        "چارصد" => "چهارصد",
+        "پونصد" => "پانصد",
+        "شیشصد" | "شیصد" => "ششصد",
+        "هفصد" => "هفتصد",
+        "هشصد" => "هشتصد",
+        "الف" | "آلاف" | "الاف" => "هزار",
+        "ملیون" => "میلیون",
+        "ملیارد" => "میلیارد",
+        _ => return None,
+    })
+}
+
+/// Currency names that may follow an amount
+const CURRENCIES: [&str; 3] = ["تومان", "تومن", "ریال"];
+
+/// "صد و خورده ای" means a bit more than a hundred
+const APPROXIMATE_WORDS: [&str; 3] = ["خورده", "خرده", "ای"];
+
+fn fraction_value(word: &str) -> Option<f64> {
+    match word {
+        "نیم" | "نصف" => Some(0.5),
+        "ربع" => Some(0.25),
+        _ => None,
+    }
+}
+
+/// A standard word and the token of the input that it comes from
+struct Word<'a> {
+    text: String,
+    source: Token<'a>,
+}
+
+/// Parses colloquial and mixed Persian number phrases, the result can have a fraction
+///
+/// On top of [words_to_number](super::words_to_number) these are accepted:
+/// - colloquial spellings such as "یه"، "دویس"، "پونصد" and attached "و" as in "سیصدو"
+/// - Arabic spellings such as "ثلاث" and Arabic letters "ي"، "ك"، "ة"
+/// - "نیم" and "ربع" fractions and decimal digits: "دو و نیم میلیون"، "۲٫۵ میلیون"
+/// - a currency name at the end: "تومان"، "تومن" or "ریال"
+/// - "خورده ای" (a bit more), which is ignored
+///
+/// As in spoken prices, a chunk after millions or larger magnitudes belongs to the next magnitude,
+/// so "دو میلیون و سیصد" is 2,300,000. Use [words_to_number](super::words_to_number) for formal texts.
+///
+/// # Examples
+///
+/// ```
+/// use rust_persian_tools::words_to_number::colloquial_words_to_number;
+///
+/// assert_eq!(colloquial_words_to_number("۲ میلیون و ۳۰۰ تومن"), Ok(2_300_000.0));
+/// assert_eq!(colloquial_words_to_number("دو و نیم میلیون"), Ok(2_500_000.0));
+/// assert_eq!(colloquial_words_to_number("یه میلیون"), Ok(1_000_000.0));
+/// assert_eq!(colloquial_words_to_number("صد و خورده ای"), Ok(100.0));
+/// assert_eq!(colloquial_words_to_number("ثلاث"), Ok(3.0));
+/// assert_eq!(colloquial_words_to_number("سه ربع"), Ok(0.75));
+/// assert_eq!(colloquial_words_to_number("منفی دو و نیم"), Ok(-2.5));
+/// ```
+pub fn colloquial_words_to_number(words: impl AsRef<str>) -> Result<f64, WordsToNumberError> {
+    let input = words.as_ref();
+    // same byte lengths, so spans of the tokens are valid for the input as well
+    let normalized = input
+        .replace(['ي', 'ى'], "ی")
+        .replace('ك', "ک")
+        .replace('ة', "ه")
+        .replace(['أ', 'إ'], "ا");
+    let tokens = tokenize(&normalized);
+    // the sign is read apart, so fractions and elided magnitudes are added to the absolute value
+    let (sign, tokens) = match tokens.split_first() {
+        Some((first, rest)) if first.text == NEGATIVE_PREFIX => {
+            if rest.is_empty() {
+                return Err(unexpected(&Token {
+                    text: &input[first.span.clone()],
+                    span: first.span.clone(),
+                }));
+            }
+            (-1.0, rest)
+        }
+        _ => (1.0, tokens.as_slice()),
+    };
+
+    let mut words = Vec::with_capacity(tokens.len());
+    let mut fraction = None;
+    for Token { text, span } in tokens.iter().cloned() {
+        let token = Token {
+            text: &input[span.clone()],
+            span,
+        };
+        let standard = if let Some((integer, decimals)) = decimal_digits(text) {
+            if fraction.is_some() {
+                return Err(unexpected(&token));
+            }
+            fraction = Some((decimals, words.len() + 1));
+            vec![integer]
+        } else if let Some(standard) = standard_word(text) {
+            vec![standard.to_string()]
+        } else if let Some(standard) = text
+            .strip_suffix(AND)
+            .filter(|word| !is_known(text) && is_known(word))
+        {
+            // "سیصدو" -> "سیصد و"
+            let standard = standard_word(standard).unwrap_or(standard);
+            vec![standard.to_string(), AND.to_string()]
+        } else {
+            vec![text.to_string()]
+        };
+
+        words.extend(standard.into_iter().map(|text| Word {
+            text,
+            source: token.clone(),
+        }));
+    }
+
+    if words
+        .last()
+        .is_some_and(|word| CURRENCIES.contains(&word.text.as_str()))
+    {
+        words.pop();
+    }
+    if let Some(index) = words
+        .iter()
+        .position(|word| APPROXIMATE_WORDS.contains(&word.text.as_str()))
+    {
+        let mut start = index;
+        if start > 0 && words[start - 1].text == AND {
+            start -= 1;
+        }
+        let end = words[index..]
+            .iter()
+            .take_while(|word| APPROXIMATE_WORDS.contains(&word.text.as_str()))
+            .count()
+            + index;
+        words.drain(start..end);
+    }
+    if let Some(index) = words
+        .iter()
+        .position(|word| fraction_value(&word.text).is_some())
+    {
+        if fraction.is_some() {
+            return Err(unexpected(&words[index].source));
+        }
+        let mut value = fraction_value(&words[index].text).unwrap_or_default();
+        let mut start = index;
+        // "سه ربع"
+        if value == 0.25 && start > 0 && words[start - 1].text == "سه" {
+            value = 0.75;
+            start -= 1;
+        }
+        if start > 0 && words[start - 1].text == AND {
+            start -= 1;
+        }
+        words.drain(start..=index);
+        fraction = Some((value, start));
+    }
+
+    let Some((fraction, position)) = fraction else {
+        return Ok(sign * with_elided_magnitude(&words)? as f64);
+    };
+
+    let (integer, magnitudes) = words.split_at(position);
+    // "دو میلیون و نیم هزار"
+    if let Some(word) = magnitudes
+        .first()
+        .filter(|_| integer.iter().any(|word| magnitude_of(word).is_some()))
+    {
+        return Err(unexpected(&word.source));
+    }
+    let integer = match integer {
+        [] => 0,
+        integer => parse_words(integer)?,
+    };
+    let mut magnitude: i64 = 1;
+    for word in magnitudes {
+        magnitude = get_magnitude_number(&word.text)
+            .and_then(|value| magnitude.checked_mul(value))
+            .ok_or(unexpected(&word.source))?;
+    }
+
+    if magnitudes.is_empty() {
+        // "دو میلیون و نیم" is two and half millions
+        Ok(sign * (integer as f64 + fraction * last_magnitude(&words) as f64))
+    } else {
+        Ok(sign * (integer as f64 + fraction) * magnitude as f64)
+    }
+}
+
+/// "2.5" -> ("2", 0.5)
+fn decimal_digits(text: &str) -> Option<(String, f64)> {
+    let digits = text.digits_fa_to_en().digits_ar_to_en().replace('٫', ".");
+    let (integer, decimals) = digits.split_once('.')?;
+    if decimals.is_empty() || !(integer.bytes().chain(decimals.bytes())).all(|b| b.is_ascii_digit())
+    {
+        return None;
+    }
+
+    let decimals = format!("0.{decimals}").parse::<f64>().ok()?;
+    let integer = match integer {
+        "" => "0".to_string(),
+        integer => integer.to_string(),
+    };
+    Some((integer, decimals))
+}
+
+fn is_known(word: &str) -> bool {
+    standard_word(word).is_some()
+        || parse(word).is_ok()
+        || fraction_value(word).is_some()
+        || CURRENCIES.contains(&word)
+}
+
+fn unexpected(token: &Token) -> WordsToNumberError {
+    WordsToNumberError::UnexpectedToken {
+        token: token.text.to_string(),
+        span: token.span.clone(),
+    }
+}
+
+/// Parses standard words and maps the error back to the input
+fn parse_words(words: &[Word]) -> Result<i64, WordsToNumberError> {
+    let text = words
+        .iter()
+        .map(|word| word.text.as_str())
+        .collect::<Vec<_>>()
+        .join(" ");
+
+    parse(&text).map_err(|error| {
+        let Some(span) = error.span() else {
+            return error;
+        };
+        // index of the word that contains the error
+        let index = text[..span.start].split(' ').count() - 1;
+        let Some(source) = words.get(index).map(|word| word.source.clone()) else {
+            return error;
+        };
+        let token = source.text.to_string();
+        let span = source.span;
+
+        match error {
+            WordsToNumberError::InvalidUnit { .. } => {
+                WordsToNumberError::InvalidUnit { token, span }
+            }
+            WordsToNumberError::Overflow { .. } => WordsToNumberError::Overflow { token, span },
+            _ => WordsToNumberError::UnexpectedToken { token, span },
+        }
+    })
+}
+
+fn magnitude_of(word: &Word) -> Option<i64> {
+    get_magnitude_number(&without_ordinal_suffix(&word.text))
+}
+
+/// Magnitude of the last group, e.g. a million for "دو میلیون"
+fn last_magnitude(words: &[Word]) -> i64 {
+    words
+        .iter()
+        .rev()
+        .take_while(|word| magnitude_of(word).is_some())
+        .filter_map(magnitude_of)
+        .product()
+}
+
+/// "دو میلیون و سیصد" -> 2,300,000
+fn with_elided_magnitude(words: &[Word]) -> Result<i64, WordsToNumberError> {
+    let value = parse_words(words)?;
+
+    let Some(last) = words.iter().rposition(|word| magnitude_of(word).is_some()) else {
+        return Ok(value);
+    };
+    let magnitude = last_magnitude(&words[..=last]);
+    let tail = match &words[last + 1..] {
+        [and, tail @ ..] if and.text == AND => tail,
+        tail => tail,
+    };
+    if magnitude < 1_000_000 || tail.is_empty() {
+        return Ok(value);
+    }
+
+    let chunk = parse_words(tail)?;
+    if !(0..1000).contains(&chunk) {
+        return Ok(value);
+    }
+    value
+        .checked_add(chunk * (magnitude / 1000 - 1))
+        .ok_or_else(|| {
+            let source = &words[words.len() - 1].source;
+            WordsToNumberError::Overflow {
+                token: source.text.to_string(),
+                span: source.span.clone(),
+            }
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn colloquial_test() {
+        assert_eq!(colloquial_words_to_number("سیصدو پنجاه"), Ok(350.0));
+        assert_eq!(colloquial_words_to_number("دویس و پنجا"), Ok(250.0));
+        assert_eq!(colloquial_words_to_number("پونصد تومن"), Ok(500.0));
+        assert_eq!(colloquial_words_to_number("ثلاثة آلاف"), Ok(3000.0));
+        assert_eq!(colloquial_words_to_number("اربعين"), Ok(40.0));
+        assert_eq!(
+            colloquial_words_to_number("صد و خورده‌ای هزار"),
+            Ok(100_000.0)
+        );
+        assert_eq!(
+            colloquial_words_to_number("یه ملیون و دویست هزار"),
+            Ok(1_200_000.0)
+        );
+    }
+
+    #[test]
+    fn fraction_test() {
+        assert_eq!(colloquial_words_to_number("نیم"), Ok(0.5));
+        assert_eq!(colloquial_words_to_number("ده و نیم"), Ok(10.5));
+        assert_eq!(colloquial_words_to_number("یک و ربع"), Ok(1.25));
+        assert_eq!(colloquial_words_to_number("دو و سه ربع"), Ok(2.75));
+        assert_eq!(
+            colloquial_words_to_number("دو میلیون و نیم"),
+            Ok(2_500_000.0)
+        );
+        assert_eq!(colloquial_words_to_number("نیم میلیون"), Ok(500_000.0));
+        assert_eq!(
+            colloquial_words_to_number("۱٫۵ میلیارد"),
+            Ok(1_500_000_000.0)
+        );
+        assert_eq!(colloquial_words_to_number("2.25"), Ok(2.25));
+    }
+
+    #[test]
+    fn elided_magnitude_test() {
+        assert_eq!(
+            colloquial_words_to_number("یک میلیون و پانصد"),
+            Ok(1_500_000.0)
+        );
+        assert_eq!(
+            colloquial_words_to_number("یک میلیارد و دویست"),
+            Ok(1_200_000_000.0)
+        );
+        assert_eq!(colloquial_words_to_number("دو هزار و سیصد"), Ok(2300.0));
+        assert_eq!(
+            colloquial_words_to_number("دو میلیون و سیصد هزار"),
+            Ok(2_300_000.0)
+        );
+    }
+
+    #[test]
+    fn negative_test() {
+        assert_eq!(colloquial_words_to_number("منفی دو"), Ok(-2.0));
+        assert_eq!(colloquial_words_to_number("منفی دو و نیم"), Ok(-2.5));
+        assert_eq!(
+            colloquial_words_to_number("منفی ۲٫۵ میلیون"),
+            Ok(-2_500_000.0)
+        );
+        assert_eq!(
+            colloquial_words_to_number("منفی دو میلیون و سیصد"),
+            Ok(-2_300_000.0)
+        );
+        assert_eq!(
+            colloquial_words_to_number("منفی دو میلیون و نیم"),
+            Ok(-2_500_000.0)
+        );
+        assert_eq!(
+            colloquial_words_to_number("منفی"),
+            Err(WordsToNumberError::UnexpectedToken {
+                token: "منفی".to_string(),
+                span: 0..8
+            })
+        );
+    }
+
+    #[test]
+    fn colloquial_errors_test() {
+        assert_eq!(
+            colloquial_words_to_number(""),
+            Err(WordsToNumberError::EmptyInput)
+        );
+        assert_eq!(
+            colloquial_words_to_number("یه سیب"),
+            Err(WordsToNumberError::InvalidUnit {
+                token: "سیب".to_string(),
+                span: 5..11
+            })
+        );
+        assert_eq!(
+            colloquial_words_to_number("هزار يه هزار"),
+            Err(WordsToNumberError::UnexpectedToken {
+                token: "يه".to_string(),
+                span: 9..13
+            })
+        );
+        assert!(colloquial_words_to_number("نیم و نیم").is_err());
+        assert!(colloquial_words_to_number("۱٫۵ و نیم").is_err());
+        assert!(colloquial_words_to_number("دو و نیم سه").is_err());
+        assert_eq!(
+            colloquial_words_to_number("دو میلیون و نیم هزار"),
+            Err(WordsToNumberError::UnexpectedToken {
+                token: "هزار".to_string(),
+                span: 28..36
+            })
+        );
+    }
+}
@@ -1,4 +1,9 @@
 pub(super) const NEGATIVE_PREFIX: &str = "منفی";
+pub(super) const AND: &str = "و";
+pub(super) const NEGATIVE_PREFIXES_EN: [&str; 2] = ["minus", "negative"];
+pub(super) const AND_EN: &str = "and";
+/// Ordinal suffixes that are written as separate words, e.g. "سی اُم"
+pub(super) const ORDINAL_WORDS: [&str; 4] = ["ام", "اُم", "امین", "اُمین"];
 
 pub(super) fn get_unit_number(unit: &str) -> Option<i64> {
     Some(match unit {
@@ -41,6 +46,36 @@ pub(super) fn get_unit_number(unit: &str) -> Option<i64> {
         "هفتصد" => 700,
         "هشتصد" => 800,
         "نهصد" => 900,
+        // English
+        "zero" => 0,
+        "one" => 1,
+        "two" => 2,
+        "three" => 3,
+        "four" => 4,
+        "five" => 5,
+        "six" => 6,
+        "seven" => 7,
+        "eight" => 8,
+        "nine" => 9,
+        "ten" => 10,
+        "eleven" => 11,
+        "twelve" => 12,
+        "thirteen" => 13,
+        "fourteen" => 14,
+        "fifteen" => 15,
+        "sixteen" => 16,
+        "seventeen" => 17,
+        "eighteen" => 18,
+        "nineteen" => 19,
+        "twenty" => 20,
+        "thirty" => 30,
+        "forty" => 40,
+        "fifty" => 50,
+        "sixty" => 60,
+        "seventy" => 70,
+        "eighty" => 80,
+        "ninety" => 90,
+        "hundred" => 100,
         _ => return None,
     })
 }
@@ -52,6 +87,36 @@ pub(super) fn get_magnitude_number(unit: &str) -> Option<i64> {
         "بیلیون" => 1000000000,
         "میلیارد" => 1000000000,
         "تریلیون" => 1000000000000,
+        "کوآدریلیون" => 1000000000000000,
+        "کوینتیلیون" => 1000000000000000000,
+        // English
+        "thousand" => 1000,
+        "million" => 1000000,
+        "milliard" | "billion" => 1000000000,
+        "trillion" => 1000000000000,
+        "quadrillion" => 1000000000000000,
+        "quintillion" => 1000000000000000000,
+        _ => return None,
+    })
+}
+
+/// Magnitudes of the long scale, where "بیلیون" is 10^12
+#[cfg(feature = "number-to-words")]
+pub(super) fn get_long_scale_magnitude_number(unit: &str) -> Option<i64> {
+    Some(match unit {
+        "هزار" => 1000,
+        "میلیون" => 1000000,
+        "میلیارد" => 1000000000,
+        "بیلیون" => 1000000000000,
+        "بیلیارد" => 1000000000000000,
+        "تریلیون" => 1000000000000000000,
+        // English
+        "thousand" => 1000,
+        "million" => 1000000,
+        "milliard" => 1000000000,
+        "billion" => 1000000000000,
+        "billiard" => 1000000000000000,
+        "trillion" => 1000000000000000000,
         _ => return None,
     })
 }
@@ -1,10 +1,30 @@
+use std::ops::Range;
+
 use thiserror::Error;
 
+/// Errors of parsing number words\
+/// `span` is the byte range of `token` in the input
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
-#[derive(Error, Clone, Copy, Debug, Hash, PartialEq, Eq)]
+#[derive(Error, Clone, Debug, Hash, PartialEq, Eq)]
 pub enum WordsToNumberError {
-    #[error("There is an invalid unit in the input.")]
-    InvalidUnit,
+    #[error("There is an invalid unit \"{token}\" in the input at {span:?}.")]
+    InvalidUnit { token: String, span: Range<usize> },
+    #[error("The word \"{token}\" at {span:?} is not expected here.")]
+    UnexpectedToken { token: String, span: Range<usize> },
+    #[error("The number overflows at \"{token}\" at {span:?}.")]
+    Overflow { token: String, span: Range<usize> },
     #[error("The input cannot be an empty string.")]
     EmptyInput,
 }
+
+impl WordsToNumberError {
+    /// Byte range of the offending token in the input, if any
+    pub fn span(&self) -> Option<Range<usize>> {
+        match self {
+            WordsToNumberError::InvalidUnit { span, .. }
+            | WordsToNumberError::UnexpectedToken { span, .. }
+            | WordsToNumberError::Overflow { span, .. } => Some(span.clone()),
+            WordsToNumberError::EmptyInput => None,
+        }
+    }
+}
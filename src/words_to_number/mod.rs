@@ -1,22 +1,24 @@
+mod colloquial;
 mod constants;
 pub mod errors;
+mod parser;
+mod scan;
 
 use crate::{
     commas::add_commas::add_commas_mut,
-    digits::{DigitsAr2En, DigitsEn2ArMut, DigitsEn2FaMut, DigitsFa2En},
-    remove_ordinal_suffix::remove_ordinal_suffix,
+    digits::{DigitsEn2ArMut, DigitsEn2FaMut},
 };
 
-use self::constants::{get_magnitude_number, get_unit_number, NEGATIVE_PREFIX};
+pub use self::colloquial::colloquial_words_to_number;
+#[cfg(feature = "number-to-words")]
+use self::constants::get_long_scale_magnitude_number;
+use self::constants::ORDINAL_WORDS;
 pub use self::errors::WordsToNumberError;
-
-#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
-#[derive(PartialEq, Eq, Debug, Clone, Copy, Hash)]
-pub enum Language {
-    Arabic,
-    Persian,
-    English,
-}
+use self::parser::{tokenize, without_ordinal_suffix};
+pub use self::scan::{find_numbers, replace_numbers, NumberMatch};
+pub use crate::digits::Language;
+#[cfg(feature = "number-to-words")]
+use crate::number_to_words::Scale;
 
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[derive(PartialEq, Eq, Debug, Clone, Copy, Hash)]
@@ -34,72 +36,121 @@ impl Default for Options {
     }
 }
 
-fn calculate(tokens: Vec<String>) -> Result<i64, WordsToNumberError> {
-    let mut sum = 0;
-    let mut is_negative = false;
-
-    for token in tokens {
-        if token == NEGATIVE_PREFIX {
-            // check negative token
-            is_negative = true;
-        } else if let Some(value) = get_unit_number(&token) {
-            // if token is a valid number
-            sum += value;
-        } else if let Some(value) = get_magnitude_number(&token) {
-            // if token is a magnitude valid number
-            if sum == 0 {
-                sum = value;
-            } else {
-                sum *= value;
-            }
-        } else if let Ok(value) = token.digits_fa_to_en().digits_ar_to_en().parse::<i64>() {
-            sum += value;
-        } else {
-            return Err(WordsToNumberError::InvalidUnit);
-        }
-    }
-
-    if is_negative {
-        Ok(-sum)
-    } else {
-        Ok(sum)
-    }
-}
-
 /// returns a i64 number if the givin input is a standard persian number text otherwise it would return a error
 ///
 /// ordinal suffix is supported for example "منفی سی اُم"
 ///
 /// if you need to change numbers format for example add commas or change numbers to arabic or persian as result you may use [words_to_number_str]
 ///
+/// english words such as "twenty-one thousand" are accepted as well
+///
+/// the order of magnitudes is validated, so "هزار هزار" is an error\
+/// a single digit right after a magnitude must be joined with "و", so "دو هزار سه" is an error while "دو هزار و سه" is 2003\
+/// errors contain the offending word and its byte range in the input
+///
 /// # Examples
 ///
 /// ```
-/// use rust_persian_tools::words_to_number::words_to_number;
+/// use rust_persian_tools::words_to_number::{words_to_number, WordsToNumberError};
 ///
 /// assert_eq!(words_to_number("منفی سه هزار").unwrap(), -3000);
+/// assert_eq!(words_to_number("minus three thousand").unwrap(), -3000);
 /// assert!(words_to_number("سلام چطوری").is_err());
+/// assert_eq!(
+///     words_to_number("دو میلیون و سه میلیارد"),
+///     Err(WordsToNumberError::UnexpectedToken {
+///         token: "میلیارد".to_string(),
+///         span: 26..40
+///     })
+/// );
 /// ```
 pub fn words_to_number(words: impl AsRef<str>) -> Result<i64, WordsToNumberError> {
-    let words = words.as_ref().trim();
+    parser::parse(words.as_ref())
+}
+
+/// same as [words_to_number] but magnitudes are named in the given [Scale]
+///
+/// [words_to_number] reads "بیلیون" as 10^9, so texts of [Scale::Long] (where it is 10^12)
+/// must be parsed with this function, the other scales are parsed the same as [words_to_number]
+///
+/// # Examples
+///
+/// ```
+/// use rust_persian_tools::number_to_words::Scale;
+/// use rust_persian_tools::words_to_number::{words_to_number, words_to_number_with_scale};
+///
+/// assert_eq!(words_to_number("یک بیلیون").unwrap(), 1_000_000_000);
+/// assert_eq!(
+///     words_to_number_with_scale("یک بیلیون", Scale::Long).unwrap(),
+///     1_000_000_000_000
+/// );
+/// assert_eq!(
+///     words_to_number_with_scale("دو بیلیارد و سه میلیارد", Scale::Long).unwrap(),
+///     2_000_003_000_000_000
+/// );
+/// ```
+#[cfg(feature = "number-to-words")]
+pub fn words_to_number_with_scale(
+    words: impl AsRef<str>,
+    scale: Scale,
+) -> Result<i64, WordsToNumberError> {
+    match scale {
+        Scale::Iranian | Scale::Short => parser::parse(words.as_ref()),
+        Scale::Long => parser::parse_with(words.as_ref(), get_long_scale_magnitude_number),
+    }
+}
+
+/// A number parsed by [words_to_number_ordinal]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(PartialEq, Eq, Debug, Clone, Copy, Hash)]
+pub struct ParsedNumber {
+    pub value: i64,
+    /// true if the input was an ordinal such as "بیست و یکم" or "نخستین"
+    pub is_ordinal: bool,
+}
 
-    if words.is_empty() {
+/// same as [words_to_number] but also tells whether the input was ordinal
+///
+/// both "-م" and "-مین" forms are supported, as well as "اول", "اولین", "نخست" and "نخستین"
+///
+/// # Examples
+///
+/// ```
+/// use rust_persian_tools::words_to_number::{words_to_number_ordinal, ParsedNumber};
+///
+/// assert_eq!(
+///     words_to_number_ordinal("بیست و یکمین").unwrap(),
+///     ParsedNumber { value: 21, is_ordinal: true }
+/// );
+/// assert_eq!(
+///     words_to_number_ordinal("نخست").unwrap(),
+///     ParsedNumber { value: 1, is_ordinal: true }
+/// );
+/// assert_eq!(
+///     words_to_number_ordinal("سی و سه").unwrap(),
+///     ParsedNumber { value: 33, is_ordinal: false }
+/// );
+/// ```
+pub fn words_to_number_ordinal(words: impl AsRef<str>) -> Result<ParsedNumber, WordsToNumberError> {
+    let words = words.as_ref();
+    let tokens = tokenize(words);
+
+    let Some(last) = tokens.last() else {
         return Err(WordsToNumberError::EmptyInput);
+    };
+    if tokens.len() == 1 && matches!(last.text, "اول" | "اولین" | "نخست" | "نخستین")
+    {
+        return Ok(ParsedNumber {
+            value: 1,
+            is_ordinal: true,
+        });
     }
 
-    // remove ordinal suffix from each word
-    let tokens = words
-        .replace("شیش صد", "ششصد")
-        .replace("شش صد", "ششصد")
-        .replace("هفت صد", "هفتصد")
-        .replace("هشت صد", "هشتصد")
-        .replace("نه صد", "نهصد")
-        .split_ascii_whitespace()
-        .filter(|word| *word != "و" && *word != "ام" && *word != "اُم")
-        .map(remove_ordinal_suffix)
-        .collect::<Vec<String>>();
-
-    calculate(tokens)
+    Ok(ParsedNumber {
+        value: words_to_number(words)?,
+        is_ordinal: ORDINAL_WORDS.contains(&last.text)
+            || without_ordinal_suffix(last.text) != last.text,
+    })
 }
 
 /// returns a number as [String] if the givin input is a standard persian number text otherwise it would return a error
@@ -129,6 +180,11 @@ pub fn words_to_number_str(
     option: &Options,
 ) -> Result<String, WordsToNumberError> {
     let number = words_to_number(words)?;
+
+    Ok(format_number(number, option))
+}
+
+fn format_number(number: i64, option: &Options) -> String {
     let mut result = format!("{number}");
 
     if option.add_commas {
@@ -141,7 +197,7 @@ pub fn words_to_number_str(
         result.digits_en_to_fa_mut();
     }
 
-    Ok(result)
+    result
 }
 
 #[cfg(test)]
@@ -153,6 +209,14 @@ mod tests {
         assert_eq!(words_to_number("منفی سه هزار").unwrap(), -3000);
         assert_eq!(words_to_number("دوازده هزار بیست دو").unwrap(), 12022);
         assert_eq!(words_to_number("دوازده هزار و بیست و دو").unwrap(), 12022);
+        assert_eq!(words_to_number("چهار صد").unwrap(), 400);
+        assert_eq!(words_to_number("یک‌صد و پنج").unwrap(), 105);
+        assert_eq!(
+            words_to_number("یک میلیون و دویست هزار").unwrap(),
+            1_200_000
+        );
+        assert_eq!(words_to_number("هزار میلیارد").unwrap(), 1_000_000_000_000);
+        assert_eq!(words_to_number("یک میلیون و هزار").unwrap(), 1_001_000);
     }
 
     #[test]
@@ -243,9 +307,52 @@ mod tests {
         assert_eq!(words_to_number("منفی سی اُم").unwrap(), -30);
     }
 
+    #[test]
+    fn words_to_number_ordinal_test() {
+        let ordinal = |value| ParsedNumber {
+            value,
+            is_ordinal: true,
+        };
+
+        assert_eq!(words_to_number_ordinal("اولین"), Ok(ordinal(1)));
+        assert_eq!(words_to_number_ordinal("یکم"), Ok(ordinal(1)));
+        assert_eq!(words_to_number_ordinal("سومین"), Ok(ordinal(3)));
+        assert_eq!(words_to_number_ordinal("سی و سوم"), Ok(ordinal(33)));
+        assert_eq!(words_to_number_ordinal("سی اُمین"), Ok(ordinal(30)));
+        assert_eq!(words_to_number_ordinal("سی‌ام"), Ok(ordinal(30)));
+        assert_eq!(words_to_number_ordinal("یک هزارم"), Ok(ordinal(1000)));
+        assert_eq!(
+            words_to_number_ordinal("منفی سه هزارمین"),
+            Ok(ordinal(-3000))
+        );
+        assert_eq!(
+            words_to_number_ordinal("۱۲"),
+            Ok(ParsedNumber {
+                value: 12,
+                is_ordinal: false
+            })
+        );
+
+        assert_eq!(
+            words_to_number_ordinal(" "),
+            Err(WordsToNumberError::EmptyInput)
+        );
+        assert_eq!(
+            words_to_number_ordinal("بیست و اول"),
+            Err(WordsToNumberError::InvalidUnit {
+                token: "اول".to_string(),
+                span: 12..18
+            })
+        );
+    }
+
     #[test]
     fn words_to_number_fail_cases() {
         assert!(words_to_number("سلام چطوری").is_err());
         assert!(words_to_number("").is_err());
+        assert!(words_to_number("هزار هزار").is_err());
+        assert!(words_to_number("صد و صد").is_err());
+        assert!(words_to_number("سه و و دو").is_err());
+        assert!(words_to_number("دو هزار سه").is_err());
     }
 }
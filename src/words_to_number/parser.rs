@@ -0,0 +1,515 @@
+use std::ops::Range;
+
+use super::constants::{
+    get_magnitude_number, get_unit_number, AND, AND_EN, NEGATIVE_PREFIX, NEGATIVE_PREFIXES_EN,
+    ORDINAL_WORDS,
+};
+use super::errors::WordsToNumberError;
+use crate::digits::{DigitsAr2En, DigitsFa2En};
+use crate::remove_ordinal_suffix::remove_ordinal_suffix;
+
+/// A word of the input and its byte range
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(super) struct Token<'a> {
+    pub(super) text: &'a str,
+    pub(super) span: Range<usize>,
+}
+
+impl Token<'_> {
+    fn invalid_unit(&self) -> WordsToNumberError {
+        WordsToNumberError::InvalidUnit {
+            token: self.text.to_string(),
+            span: self.span.clone(),
+        }
+    }
+
+    fn unexpected(&self) -> WordsToNumberError {
+        WordsToNumberError::UnexpectedToken {
+            token: self.text.to_string(),
+            span: self.span.clone(),
+        }
+    }
+
+    fn overflow(&self) -> WordsToNumberError {
+        WordsToNumberError::Overflow {
+            token: self.text.to_string(),
+            span: self.span.clone(),
+        }
+    }
+}
+
+/// Splits the input on white spaces, half-spaces and hyphens of english words such as "twenty-one"
+pub(super) fn tokenize(input: &str) -> Vec<Token<'_>> {
+    let mut tokens = Vec::new();
+    let mut start = None;
+    let mut previous = ' ';
+
+    for (index, c) in input.char_indices() {
+        let is_hyphen = c == '-' && previous.is_ascii_alphabetic();
+        previous = c;
+        if c.is_whitespace() || c == '\u{200c}' || is_hyphen {
+            if let Some(start) = start.take() {
+                tokens.push(Token {
+                    text: &input[start..index],
+                    span: start..index,
+                });
+            }
+        } else if start.is_none() {
+            start = Some(index);
+        }
+    }
+    if let Some(start) = start {
+        tokens.push(Token {
+            text: &input[start..],
+            span: start..input.len(),
+        });
+    }
+
+    tokens
+}
+
+/// "سومین" -> "سه", "هزارم" -> "هزار"
+pub(super) fn without_ordinal_suffix(word: &str) -> String {
+    let word = word
+        .strip_suffix("ین")
+        .filter(|word| word.ends_with('م'))
+        .unwrap_or(word);
+    remove_ordinal_suffix(word)
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Word {
+    Negative,
+    And,
+    Zero,
+    /// 1 to 900
+    Unit(i64),
+    Magnitude(i64),
+    Digits(i64),
+}
+
+/// Value of a magnitude word such as "میلیون" in a scale
+pub(super) type Magnitudes = fn(&str) -> Option<i64>;
+
+fn classify(token: &Token, magnitudes: Magnitudes) -> Result<Word, WordsToNumberError> {
+    let text = without_ordinal_suffix(&token.text.to_ascii_lowercase());
+
+    if text == NEGATIVE_PREFIX || NEGATIVE_PREFIXES_EN.contains(&text.as_str()) {
+        Ok(Word::Negative)
+    } else if text == AND || text == AND_EN {
+        Ok(Word::And)
+    } else if let Some(value) = get_unit_number(&text) {
+        Ok(if value == 0 {
+            Word::Zero
+        } else {
+            Word::Unit(value)
+        })
+    } else if let Some(value) = magnitudes(&text) {
+        Ok(Word::Magnitude(value))
+    } else {
+        let digits = text.digits_fa_to_en().digits_ar_to_en();
+        if digits.is_empty() || !digits.bytes().all(|b| b.is_ascii_digit()) {
+            return Err(token.invalid_unit());
+        }
+        digits
+            .parse::<i64>()
+            .map(Word::Digits)
+            .map_err(|_| token.overflow())
+    }
+}
+
+/// Place of the last unit in the chunk, a unit must come in a lower place than the previous one
+const EMPTY: u8 = 4;
+const CLOSED: u8 = 0;
+
+/// Number is read as groups such as "سیصد و بیست هزار" (chunk and magnitude)\
+/// each group must be smaller than the magnitude of the previous group
+#[derive(Clone)]
+struct Parser<'a> {
+    /// groups are subtracted from the total of negative numbers, so [i64::MIN] doesn't overflow
+    is_negative: bool,
+    total: i64,
+    /// magnitude of the last finished group
+    limit: Option<i64>,
+    chunk: i64,
+    place: u8,
+    /// product of the magnitudes of the current group, e.g. "هزار میلیارد"
+    magnitude: Option<i64>,
+    /// last token of the current group
+    last: Option<Token<'a>>,
+}
+
+impl<'a> Parser<'a> {
+    fn new(is_negative: bool) -> Self {
+        Parser {
+            is_negative,
+            total: 0,
+            limit: None,
+            chunk: 0,
+            place: EMPTY,
+            magnitude: None,
+            last: None,
+        }
+    }
+
+    fn push_unit(&mut self, value: i64, token: Token<'a>) -> Result<(), WordsToNumberError> {
+        if self.magnitude.is_some() {
+            self.finish_group()?;
+        }
+
+        // (place, place of the next unit)
+        let (place, next) = match value {
+            1..=9 => (1, 1),
+            // teens take the place of ones as well
+            10..=19 => (2, 1),
+            20..=99 => (2, 2),
+            _ => (3, 3),
+        };
+        if place >= self.place {
+            return Err(token.unexpected());
+        }
+
+        self.chunk += value;
+        self.place = next;
+        self.last = Some(token);
+        Ok(())
+    }
+
+    fn push_digits(&mut self, value: i64, token: Token<'a>) -> Result<(), WordsToNumberError> {
+        if self.magnitude.is_some() {
+            self.finish_group()?;
+        }
+        if self.place != EMPTY {
+            return Err(token.unexpected());
+        }
+
+        self.chunk = value;
+        self.place = CLOSED;
+        self.last = Some(token);
+        Ok(())
+    }
+
+    fn push_magnitude(&mut self, value: i64, token: Token<'a>) -> Result<(), WordsToNumberError> {
+        match self.magnitude {
+            // compound magnitude like "هزار میلیارد"
+            Some(magnitude) if value > magnitude => {
+                self.magnitude = Some(magnitude.checked_mul(value).ok_or(token.overflow())?);
+            }
+            // a new group like "هزار" in "یک میلیون هزار"
+            Some(_) => {
+                self.finish_group()?;
+                self.chunk = 1;
+                self.magnitude = Some(value);
+            }
+            None => {
+                // "هزار" means "یک هزار"
+                if self.place == EMPTY {
+                    self.chunk = 1;
+                }
+                self.magnitude = Some(value);
+            }
+        }
+
+        self.last = Some(token);
+        Ok(())
+    }
+
+    fn finish_group(&mut self) -> Result<(), WordsToNumberError> {
+        let Some(last) = self.last.take() else {
+            return Ok(());
+        };
+
+        let magnitude = self.magnitude.take().unwrap_or(1);
+        let value = self.chunk.checked_mul(magnitude).ok_or(last.overflow())?;
+        if self.limit.is_some_and(|limit| value >= limit) {
+            return Err(last.unexpected());
+        }
+
+        self.total = if self.is_negative {
+            self.total.checked_sub(value)
+        } else {
+            self.total.checked_add(value)
+        }
+        .ok_or(last.overflow())?;
+        self.limit = Some(magnitude);
+        self.chunk = 0;
+        self.place = EMPTY;
+        Ok(())
+    }
+}
+
+/// Reads a number word by word, so the valid prefixes of a text are found in one pass
+struct Reader<'a> {
+    parser: Parser<'a>,
+    /// "منفی" at the beginning
+    negative: Option<Token<'a>>,
+    /// number of words after "منفی"
+    count: usize,
+    previous: Option<Word>,
+    previous_and: Option<Token<'a>>,
+    /// "صفر" is only valid alone
+    zero: Option<Token<'a>>,
+}
+
+impl<'a> Reader<'a> {
+    fn new() -> Self {
+        Reader {
+            parser: Parser::new(false),
+            negative: None,
+            count: 0,
+            previous: None,
+            previous_and: None,
+            zero: None,
+        }
+    }
+
+    fn push(&mut self, word: Word, token: Token<'a>) -> Result<(), WordsToNumberError> {
+        if let Some(zero) = &self.zero {
+            return Err(zero.unexpected());
+        }
+
+        match word {
+            Word::Negative if self.count == 0 && self.negative.is_none() => {
+                self.parser.is_negative = true;
+                self.negative = Some(token);
+                return Ok(());
+            }
+            Word::Negative => return Err(token.unexpected()),
+            Word::And => {
+                if self.count == 0 || self.previous_and.is_some() {
+                    return Err(token.unexpected());
+                }
+                self.previous_and = Some(token);
+            }
+            Word::Zero if self.count == 0 => self.zero = Some(token),
+            Word::Zero => return Err(token.unexpected()),
+            // "دو هزار سه" must be written as "دو هزار و سه"
+            Word::Unit(1..=9)
+                if !token.text.is_ascii() && matches!(self.previous, Some(Word::Magnitude(_))) =>
+            {
+                return Err(token.unexpected())
+            }
+            Word::Unit(value) => self.parser.push_unit(value, token)?,
+            Word::Digits(value) => self.parser.push_digits(value, token)?,
+            Word::Magnitude(value) => self.parser.push_magnitude(value, token)?,
+        }
+
+        if word != Word::And {
+            self.previous_and = None;
+        }
+        self.previous = Some(word);
+        self.count += 1;
+        Ok(())
+    }
+
+    /// Value of the words that are read so far
+    fn value(&self) -> Result<i64, WordsToNumberError> {
+        if self.count == 0 {
+            return Err(match &self.negative {
+                Some(negative) => negative.unexpected(),
+                None => WordsToNumberError::EmptyInput,
+            });
+        }
+        if self.zero.is_some() {
+            return Ok(0);
+        }
+        if let Some(and) = &self.previous_and {
+            return Err(and.unexpected());
+        }
+
+        let mut parser = self.parser.clone();
+        parser.finish_group()?;
+        Ok(parser.total)
+    }
+}
+
+/// The longest prefix of a text that is a valid number
+pub(super) struct Prefix {
+    pub(super) value: i64,
+    /// end of the prefix in the text
+    pub(super) end: usize,
+}
+
+/// Reads `tokens` of `input` until the first invalid word\
+/// Returns the longest valid prefix and the result of all the words that are read
+pub(super) fn read<'a>(
+    input: &'a str,
+    tokens: impl IntoIterator<Item = Token<'a>>,
+    magnitudes: Magnitudes,
+) -> (Option<Prefix>, Result<i64, WordsToNumberError>) {
+    let mut reader = Reader::new();
+    let mut longest = None;
+    let mut tokens = tokens.into_iter().peekable();
+
+    while let Some(mut token) = tokens.next() {
+        if !ORDINAL_WORDS.contains(&token.text) {
+            let mut word = match classify(&token, magnitudes) {
+                Ok(word) => word,
+                Err(error) => return (longest, Err(error)),
+            };
+
+            // hundreds in two words: "چهار صد" or "four hundred"
+            if let Word::Unit(digit @ 1..=9) = word {
+                if let Some(next) = tokens.next_if(|next| {
+                    (without_ordinal_suffix(next.text) == "صد"
+                        || next.text.eq_ignore_ascii_case("hundred"))
+                        && classify(next, magnitudes) == Ok(Word::Unit(100))
+                }) {
+                    let span = token.span.start..next.span.end;
+                    token = Token {
+                        text: &input[span.clone()],
+                        span,
+                    };
+                    word = Word::Unit(digit * 100);
+                }
+            }
+
+            let end = token.span.end;
+            if let Err(error) = reader.push(word, token) {
+                return (longest, Err(error));
+            }
+            if let Ok(value) = reader.value() {
+                longest = Some(Prefix { value, end });
+            }
+        } else if let Some(longest) = &mut longest {
+            // "ام" of "سی ام" belongs to the number
+            longest.end = token.span.end;
+        }
+    }
+
+    (longest, reader.value())
+}
+
+/// Parses a whole input as a number
+pub(super) fn parse(input: &str) -> Result<i64, WordsToNumberError> {
+    parse_with(input, get_magnitude_number)
+}
+
+/// Parses a whole input as a number with the given magnitude names
+pub(super) fn parse_with(input: &str, magnitudes: Magnitudes) -> Result<i64, WordsToNumberError> {
+    let tokens = tokenize(input);
+    // unknown words are reported before the misplaced ones
+    for token in tokens.iter() {
+        if !ORDINAL_WORDS.contains(&token.text) {
+            classify(token, magnitudes)?;
+        }
+    }
+
+    read(input, tokens, magnitudes).1
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn tokenize_test() {
+        let tokens = tokenize(" سی‌ و  دو");
+        assert_eq!(
+            tokens,
+            vec![
+                Token {
+                    text: "سی",
+                    span: 1..5
+                },
+                Token {
+                    text: "و",
+                    span: 9..11
+                },
+                Token {
+                    text: "دو",
+                    span: 13..17
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn parse_test() {
+        assert_eq!(parse("صفر"), Ok(0));
+        assert_eq!(parse("منفی صفر"), Ok(0));
+        assert_eq!(parse("سیصد و بیست و یک"), Ok(321));
+        assert_eq!(parse("دو هزار و سه"), Ok(2003));
+        assert_eq!(parse("دو هزار بیست و سه"), Ok(2023));
+        assert_eq!(parse("two thousand three"), Ok(2003));
+        assert_eq!(parse("نه صد و نوزده هزار"), Ok(919_000));
+        assert_eq!(parse("هزار میلیارد و یک"), Ok(1_000_000_000_001));
+        assert_eq!(parse("یک میلیارد هزار"), Ok(1_000_001_000));
+        assert_eq!(parse("۱۲۰ هزار و 5"), Ok(120_005));
+        assert_eq!(
+            parse("نه کوینتیلیون و دویست و بیست و سه کوآدریلیون و سیصد و هفتاد و دو تریلیون و سی و شش میلیارد و هشت صد و پنجاه و چهار میلیون و هفت صد و هفتاد و پنج هزار و هشت صد و هفت"),
+            Ok(i64::MAX)
+        );
+        assert_eq!(
+            parse("منفی نه کوینتیلیون و دویست و بیست و سه کوآدریلیون و سیصد و هفتاد و دو تریلیون و سی و شش میلیارد و هشت صد و پنجاه و چهار میلیون و هفت صد و هفتاد و پنج هزار و هشت صد و هشت"),
+            Ok(i64::MIN)
+        );
+    }
+
+    #[test]
+    fn parse_english_test() {
+        assert_eq!(parse("zero"), Ok(0));
+        assert_eq!(parse("Twenty-One thousand"), Ok(21_000));
+        assert_eq!(parse("one hundred and five"), Ok(105));
+        assert_eq!(parse("minus three million forty-two"), Ok(-3_000_042));
+        assert_eq!(parse("hundred"), Ok(100));
+        assert_eq!(parse("5 thousand"), Ok(5000));
+        assert!(parse("forty-forty").is_err());
+        assert!(parse("thousand thousand").is_err());
+        assert_eq!(parse("twenty-one"), parse("بیست و یک"),);
+    }
+
+    #[test]
+    fn parse_errors_test() {
+        let unexpected = |token: &str, span: Range<usize>| {
+            Err(WordsToNumberError::UnexpectedToken {
+                token: token.to_string(),
+                span,
+            })
+        };
+
+        assert_eq!(parse("هزار هزار"), unexpected("هزار", 9..17));
+        assert_eq!(parse("سه دو"), unexpected("دو", 5..9));
+        assert_eq!(parse("دو هزار سه"), unexpected("سه", 14..18));
+        assert_eq!(parse("یک میلیون دو هزار"), unexpected("دو", 18..22));
+        assert_eq!(parse("بیست دوازده"), unexpected("دوازده", 9..21));
+        assert_eq!(
+            parse("دو میلیون و سه میلیارد"),
+            unexpected("میلیارد", 26..40)
+        );
+        assert_eq!(parse("دو هزار و ۵۰۰۰"), unexpected("۵۰۰۰", 17..25));
+        assert_eq!(parse("سه و"), unexpected("و", 5..7));
+        assert_eq!(parse("و سه"), unexpected("و", 0..2));
+        assert_eq!(parse("منفی"), unexpected("منفی", 0..8));
+        assert_eq!(parse("صفر و یک"), unexpected("صفر", 0..6));
+        assert_eq!(
+            parse("سه سیب"),
+            Err(WordsToNumberError::InvalidUnit {
+                token: "سیب".to_string(),
+                span: 5..11
+            })
+        );
+        assert_eq!(
+            parse("ده کوینتیلیون"),
+            Err(WordsToNumberError::Overflow {
+                token: "کوینتیلیون".to_string(),
+                span: 5..25
+            })
+        );
+        assert_eq!(
+            parse("99999999999999999999"),
+            Err(WordsToNumberError::Overflow {
+                token: "99999999999999999999".to_string(),
+                span: 0..20
+            })
+        );
+        assert_eq!(
+            parse("نه کوینتیلیون و دویست و بیست و سه کوآدریلیون و سیصد و هفتاد و دو تریلیون و سی و شش میلیارد و هشت صد و پنجاه و چهار میلیون و هفت صد و هفتاد و پنج هزار و هشت صد و هشت"),
+            Err(WordsToNumberError::Overflow {
+                token: "هشت".to_string(),
+                span: 281..287
+            })
+        );
+        assert_eq!(parse(" "), Err(WordsToNumberError::EmptyInput));
+    }
+}
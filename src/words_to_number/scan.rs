@@ -0,0 +1,265 @@
+use std::ops::Range;
+
+use super::constants::{get_magnitude_number, ORDINAL_WORDS};
+use super::parser::{read, tokenize, without_ordinal_suffix, Prefix, Token};
+use super::{format_number, Options};
+
+/// A number that is found in a text by [find_numbers]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(PartialEq, Eq, Debug, Clone, Hash)]
+pub struct NumberMatch {
+    pub value: i64,
+    /// true if the number is ordinal such as "سوم"
+    pub is_ordinal: bool,
+    /// byte range of the number in the text
+    pub span: Range<usize>,
+}
+
+/// Punctuation that may stick to the words, e.g. "هزار،"
+fn is_punctuation(c: char) -> bool {
+    c.is_ascii_punctuation() || "،؛؟«»".contains(c)
+}
+
+/// Decimal and date separators, which are kept between digits as in "۳.۵" or "۱۴۰۳/۰۱/۰۲"
+fn is_separator(c: char) -> bool {
+    matches!(c, '.' | '/' | '-' | '٫')
+}
+
+/// Digits with separators such as "۳.۵" or "۱۴۰۳/۰۱/۰۲", which are not integers
+fn is_separated_digits(text: &str) -> bool {
+    text.contains(is_separator)
+        && text.contains(|c: char| c.is_numeric())
+        && text.chars().all(|c| c.is_numeric() || is_separator(c))
+}
+
+/// A word of the text without the punctuation around it
+struct ScanWord<'a> {
+    token: Token<'a>,
+    /// punctuation after the word, so a number doesn't continue after it
+    ends_phrase: bool,
+}
+
+/// Words of the text without the punctuation around them
+fn words(text: &str) -> Vec<ScanWord<'_>> {
+    let mut words: Vec<ScanWord> = Vec::new();
+    for token in tokenize(text) {
+        let mut trimmed = token.text.trim_start_matches(is_punctuation);
+        // ".۵" is a decimal
+        let prefix = &token.text[..token.text.len() - trimmed.len()];
+        if prefix.ends_with(['.', '٫']) && trimmed.starts_with(|c: char| c.is_numeric()) {
+            trimmed = &token.text[prefix.len() - 1..];
+        }
+        let start = token.span.start + token.text.len() - trimmed.len();
+        let trimmed = trimmed.trim_end_matches(is_punctuation);
+
+        if start != token.span.start {
+            if let Some(previous) = words.last_mut() {
+                previous.ends_phrase = true;
+            }
+        }
+        if trimmed.is_empty() {
+            continue;
+        }
+        words.push(ScanWord {
+            token: Token {
+                text: trimmed,
+                span: start..start + trimmed.len(),
+            },
+            ends_phrase: start + trimmed.len() != token.span.end,
+        });
+    }
+    words
+}
+
+/// Finds spelled out numbers in a text, numbers written with digits are found as well
+///
+/// the longest valid phrase is taken, so "سه و چهار" is two numbers while "سی و چهار" is one\
+/// "نه" alone is skipped since it mostly means "no"\
+/// a number doesn't continue after punctuation, and decimals and dates such as "۳.۵" or "۱۴۰۳/۰۱/۰۲"
+/// are not taken as numbers
+///
+/// # Examples
+///
+/// ```
+/// use rust_persian_tools::words_to_number::{find_numbers, NumberMatch};
+///
+/// let text = "سفارش سه هزار و پانصد عدد پیچ";
+/// let numbers = find_numbers(text);
+/// assert_eq!(
+///     numbers,
+///     vec![NumberMatch {
+///         value: 3500,
+///         is_ordinal: false,
+///         span: 11..38
+///     }]
+/// );
+/// assert_eq!(&text[numbers[0].span.clone()], "سه هزار و پانصد");
+/// ```
+pub fn find_numbers(text: impl AsRef<str>) -> Vec<NumberMatch> {
+    let text = text.as_ref();
+    let words = words(text);
+
+    // index of the word after the end of the phrase of each word
+    let mut phrase_ends = vec![words.len(); words.len()];
+    for index in (0..words.len().saturating_sub(1)).rev() {
+        phrase_ends[index] = if words[index].ends_phrase {
+            index + 1
+        } else {
+            phrase_ends[index + 1]
+        };
+    }
+
+    let mut numbers = Vec::new();
+    let mut start = 0;
+    while start < words.len() {
+        let first = &words[start].token;
+
+        // "۲.۵ میلیون" is not one million
+        let after_decimal = start > 0
+            && !words[start - 1].ends_phrase
+            && is_separated_digits(words[start - 1].token.text);
+        if after_decimal && get_magnitude_number(&without_ordinal_suffix(first.text)).is_some() {
+            start += 1;
+            continue;
+        }
+
+        // the words are read once up to the first invalid word
+        let phrase = words[start..phrase_ends[start]]
+            .iter()
+            .map(|word| word.token.clone());
+        let (longest, _) = read(text, phrase, get_magnitude_number);
+        let Some(Prefix { value, end }) = longest else {
+            start += 1;
+            continue;
+        };
+
+        let len = words[start..]
+            .iter()
+            .take_while(|word| word.token.span.end <= end)
+            .count();
+        let last = &words[start + len - 1].token;
+        if len == 1 && last.text == "نه" {
+            start += 1;
+            continue;
+        }
+
+        numbers.push(NumberMatch {
+            value,
+            is_ordinal: ORDINAL_WORDS.contains(&last.text)
+                || without_ordinal_suffix(last.text) != last.text,
+            span: first.span.start..end,
+        });
+        start += len;
+    }
+
+    numbers
+}
+
+/// Replaces spelled out numbers in a text with digits formatted by [Options]\
+/// Ordinal numbers are kept as they are
+///
+/// # Examples
+///
+/// ```
+/// use rust_persian_tools::words_to_number::{replace_numbers, Language, Options};
+///
+/// let options = Options {
+///     digits: Language::Persian,
+///     add_commas: true,
+/// };
+/// assert_eq!(
+///     replace_numbers("سفارش سه هزار و پانصد عدد پیچ برای روز سوم", &options),
+///     "سفارش ۳,۵۰۰ عدد پیچ برای روز سوم"
+/// );
+/// ```
+pub fn replace_numbers(text: impl AsRef<str>, options: &Options) -> String {
+    let text = text.as_ref();
+
+    let mut result = String::with_capacity(text.len());
+    let mut end = 0;
+    for number in find_numbers(text) {
+        if number.is_ordinal {
+            continue;
+        }
+        result.push_str(&text[end..number.span.start]);
+        result.push_str(&format_number(number.value, options));
+        end = number.span.end;
+    }
+    result.push_str(&text[end..]);
+
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::digits::Language;
+
+    fn values(text: &str) -> Vec<(i64, &str)> {
+        find_numbers(text)
+            .into_iter()
+            .map(|number| (number.value, &text[number.span]))
+            .collect()
+    }
+
+    #[test]
+    fn find_numbers_test() {
+        assert_eq!(values(""), vec![]);
+        assert_eq!(values("سلام"), vec![]);
+        assert_eq!(
+            values("سه و چهار و سی و چهار"),
+            vec![(3, "سه"), (4, "چهار"), (34, "سی و چهار")]
+        );
+        assert_eq!(
+            values("قیمت: «دو میلیون و ۳۰۰ هزار»، تخفیف ۱۰ درصد"),
+            vec![(2_300_000, "دو میلیون و ۳۰۰ هزار"), (10, "۱۰")]
+        );
+        assert_eq!(values("هزار هزار"), vec![(1000, "هزار"), (1000, "هزار")]);
+        assert_eq!(values("نه، سی‌ و سه تا"), vec![(33, "سی‌ و سه")]);
+        assert_eq!(values("منفی پنج درجه"), vec![(-5, "منفی پنج")]);
+        assert_eq!(values("منفی و"), vec![]);
+
+        // punctuation ends a number
+        assert_eq!(values("بیست، و سه"), vec![(20, "بیست"), (3, "سه")]);
+        assert_eq!(values("سی «و سه»"), vec![(30, "سی"), (3, "سه")]);
+        assert_eq!(values("سی ام، دو"), vec![(30, "سی ام"), (2, "دو")]);
+
+        // decimals and dates are not integers
+        assert_eq!(values("۳.۵ کیلو"), vec![]);
+        assert_eq!(values("وزن .۵ کیلو"), vec![]);
+        assert_eq!(values("۲٫۵ میلیون و ۳.۵ هزار"), vec![]);
+        assert_eq!(values("تاریخ ۱۴۰۳/۰۱/۰۲ و ۱۴۰۳-۰۱-۰۳"), vec![]);
+        assert_eq!(values("ساعت ۱۰، ۱۴۰۳/۰۱/۰۲."), vec![(10, "۱۰")]);
+
+        let numbers = find_numbers("روز بیست و سوم");
+        assert_eq!(numbers.len(), 1);
+        assert_eq!(numbers[0].value, 23);
+        assert!(numbers[0].is_ordinal);
+    }
+
+    #[test]
+    fn find_numbers_long_text_test() {
+        // every word is read a few times at most
+        let text = "سی و ".repeat(50_000);
+        assert_eq!(find_numbers(&text).len(), 50_000);
+        let text = "هزار ".repeat(50_000);
+        assert_eq!(find_numbers(&text).len(), 50_000);
+    }
+
+    #[test]
+    fn replace_numbers_test() {
+        let options = Options::default();
+        assert_eq!(replace_numbers("", &options), "");
+        assert_eq!(replace_numbers("دو و نیم کیلو", &options), "2 و نیم کیلو");
+        assert_eq!(
+            replace_numbers(
+                "۱۲ هزار و پانصد تومان",
+                &Options {
+                    digits: Language::Arabic,
+                    add_commas: true
+                }
+            ),
+            "١٢,٥٠٠ تومان"
+        );
+    }
+}